@@ -0,0 +1,99 @@
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use rust_playground::FINDERS;
+use std::hint::black_box;
+
+// deterministic word list: every 4th candidate is a shuffled anagram of the target
+fn candidates(target: &str, count: usize, seed: u64) -> Vec<String> {
+    let mut state = seed;
+    let mut next = move || {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (state >> 33) as usize
+    };
+    (0..count)
+        .map(|i| {
+            if i % 4 == 0 {
+                let mut letters: Vec<char> = target.chars().collect();
+                let shift = next() % letters.len();
+                letters.rotate_left(shift);
+                letters.into_iter().collect()
+            } else {
+                // mix of same-length and different-length candidates
+                let len = target.len() + next() % 3;
+                (0..len)
+                    .map(|_| (b'a' + (next() % 26) as u8) as char)
+                    .collect()
+            }
+        })
+        .collect()
+}
+
+fn bench_shapes(c: &mut Criterion) {
+    // (name, target word, candidate count)
+    let shapes = [
+        ("small/short", "stop", 10),
+        ("medium/short", "stop", 1_000),
+        ("large/short", "stop", 100_000),
+        ("few/long", "internationalization", 10),
+        ("many/long", "internationalization", 10_000),
+    ];
+
+    for (shape, target, count) in shapes {
+        let owned = candidates(target, count, count as u64);
+        let words: Vec<&str> = owned.iter().map(String::as_str).collect();
+
+        let mut group = c.benchmark_group(shape);
+        group.throughput(Throughput::Elements(count as u64));
+        for finder in FINDERS {
+            group.bench_with_input(
+                BenchmarkId::from_parameter(finder.name()),
+                &words,
+                |b, words| b.iter(|| finder.find(black_box(target), black_box(words))),
+            );
+        }
+        #[cfg(feature = "rayon")]
+        group.bench_with_input(
+            BenchmarkId::from_parameter("parallel"),
+            &words,
+            |b, words| {
+                b.iter(|| {
+                    rust_playground::anagrams_for_parallel(black_box(target), black_box(words))
+                })
+            },
+        );
+        group.finish();
+    }
+}
+
+// many queries against one list whose lengths spread over 1..=200 bytes, so
+// each length bucket holds only 1/200 of the candidates
+fn bench_wide_lengths(c: &mut Criterion) {
+    let owned: Vec<String> = (0..20_000)
+        .map(|i| "stop".repeat(50)[..1 + i % 200].to_string())
+        .collect();
+    let words: Vec<&str> = owned.iter().map(String::as_str).collect();
+    let queries = ["stop", "listen", "internationalization"];
+
+    let mut group = c.benchmark_group("wide-lengths");
+    group.throughput(Throughput::Elements((queries.len() * words.len()) as u64));
+    group.bench_function("v2", |b| {
+        b.iter(|| {
+            for query in queries {
+                black_box(rust_playground::anagrams_for_v2(query, black_box(&words)));
+            }
+        })
+    });
+    let buckets = rust_playground::LengthBuckets::build(&words);
+    group.bench_function("v4-buckets", |b| {
+        b.iter(|| {
+            for query in queries {
+                black_box(buckets.anagrams_for(black_box(query)));
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_shapes, bench_wide_lengths);
+criterion_main!(benches);
@@ -1,18 +1,15 @@
 use std::collections::HashSet;
+
+use crate::anagram_v2::anagram_key;
 pub fn anagrams_for<'a>(word: &str, possible_anagrams: &[&'a str]) -> HashSet<&'a str> {
-    let word_sorted = get_sorted(&word.to_lowercase());
+    let word_key = anagram_key(word);
     possible_anagrams
         .iter()
         .filter(|&anagram| {
             anagram.len() == word.len()
                 && anagram.to_lowercase() != word.to_lowercase()
-                && word_sorted == get_sorted(&anagram.to_lowercase())
+                && word_key == anagram_key(anagram)
         })
         .copied()
         .collect()
 }
-fn get_sorted(word: &str) -> Vec<char> {
-    let mut word_sorted: Vec<char> = word.chars().collect();
-    word_sorted.sort_unstable();
-    word_sorted
-}
@@ -0,0 +1,20 @@
+use std::collections::HashSet;
+
+#[cfg(feature = "impl-ascii")]
+use crate::anagram_ascii::{anagrams_for_ascii, ascii_anagram_key};
+
+// picks an implementation from the input, always with anagrams_for's results:
+// - word is all a-z (ASCII, any case): ascii count keys, fastest at every
+//   criterion shape from 10 to 100k candidates
+// - anything else: v2
+// candidate count doesn't change the choice since no crossover showed up in
+// the benches; the unicode path isn't used because NFC normalization changes
+// which words match, and rayon was slower than v2 on the one-core box it was
+// measured on. Without the impl-ascii feature every word goes to v2
+pub fn anagrams_for_auto<'a>(word: &str, possible_anagrams: &[&'a str]) -> HashSet<&'a str> {
+    #[cfg(feature = "impl-ascii")]
+    if ascii_anagram_key(word).is_some() {
+        return anagrams_for_ascii(word, possible_anagrams);
+    }
+    crate::anagram_v2::anagrams_for(word, possible_anagrams)
+}
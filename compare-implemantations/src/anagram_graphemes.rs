@@ -0,0 +1,30 @@
+use std::collections::HashSet;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Compares multisets of extended grapheme clusters instead of chars, so a flag
+/// emoji or a letter with combining marks is one unit and can't be split apart.
+/// Segmenting allocates a `Vec<&str>` per word and is noticeably slower than the
+/// char-based versions; only compiled with the `graphemes` feature.
+pub fn anagrams_for_graphemes<'a>(word: &str, possible_anagrams: &[&'a str]) -> HashSet<&'a str> {
+    let lower_word = word.to_lowercase();
+    let word_sorted = get_sorted(&lower_word);
+    possible_anagrams
+        .iter()
+        .filter(|anagram_candidate| {
+            // same bytes are still required, so the cheap check still holds
+            if anagram_candidate.len() != word.len() {
+                return false;
+            }
+            let lower_anagram_candidate = anagram_candidate.to_lowercase();
+            lower_anagram_candidate != lower_word
+                && word_sorted == get_sorted(&lower_anagram_candidate)
+        })
+        .copied()
+        .collect()
+}
+
+fn get_sorted(word: &str) -> Vec<&str> {
+    let mut word_sorted: Vec<&str> = word.graphemes(true).collect();
+    word_sorted.sort_unstable();
+    word_sorted
+}
@@ -0,0 +1,40 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crate::anagram_v2::anagram_key;
+
+// groups come out in order of first appearance, words keep their input order;
+// min_size 1 returns every group, 2 only words that have at least one anagram
+pub fn group_anagrams<'a>(words: &[&'a str], min_size: usize) -> Vec<Vec<&'a str>> {
+    let mut group_index: HashMap<String, usize> = HashMap::new();
+    let mut groups: Vec<Vec<&'a str>> = Vec::new();
+    for &word in words {
+        let index = *group_index.entry(anagram_key(word)).or_insert_with(|| {
+            groups.push(Vec::new());
+            groups.len() - 1
+        });
+        groups[index].push(word);
+    }
+    groups.retain(|group| group.len() >= min_size);
+    groups
+}
+
+// group size -> number of anagram classes of that size; repeated words count
+// as separate members, same as in group_anagrams
+pub fn anagram_histogram(words: &[&str]) -> BTreeMap<usize, usize> {
+    let mut histogram = BTreeMap::new();
+    for group in group_anagrams(words, 1) {
+        *histogram.entry(group.len()).or_insert(0) += 1;
+    }
+    histogram
+}
+
+// ties go to the class that appears first; empty input gives an empty vec
+pub fn largest_anagram_group<'a>(words: &[&'a str]) -> Vec<&'a str> {
+    let mut largest: Vec<&'a str> = Vec::new();
+    for group in group_anagrams(words, 1) {
+        if group.len() > largest.len() {
+            largest = group;
+        }
+    }
+    largest
+}
@@ -0,0 +1,123 @@
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::io::{self, Read, Write};
+
+use crate::anagram_v2::get_sorted;
+
+// identifies a saved index and its format version
+const MAGIC: &[u8; 4] = b"AIX1";
+
+// sorted keys are computed once per candidate at build time,
+// so each query only sorts the target word and does one lookup.
+// candidates are borrowed after build and owned after load
+pub struct AnagramIndex<'a> {
+    // key -> (lowercased candidate, original candidate)
+    buckets: HashMap<Vec<char>, Vec<(String, Cow<'a, str>)>>,
+}
+
+impl<'a> AnagramIndex<'a> {
+    pub fn build(candidates: &[&'a str]) -> Self {
+        let mut buckets: HashMap<Vec<char>, Vec<(String, Cow<'a, str>)>> = HashMap::new();
+        for &candidate in candidates {
+            let lower_candidate = candidate.to_lowercase();
+            buckets
+                .entry(get_sorted(&lower_candidate))
+                .or_default()
+                .push((lower_candidate, Cow::Borrowed(candidate)));
+        }
+        AnagramIndex { buckets }
+    }
+
+    // same test as anagrams_for: lowercasing can change byte lengths (the
+    // Kelvin sign lowercases to 'k'), so the original spellings must match in
+    // byte length as well as share a key
+    pub fn matches(&self, word: &str) -> HashSet<&str> {
+        let lower_word = word.to_lowercase();
+        self.buckets
+            .get(&get_sorted(&lower_word))
+            .into_iter()
+            .flatten()
+            .filter(|(lower_candidate, candidate)| {
+                candidate.len() == word.len() && *lower_candidate != lower_word
+            })
+            .map(|(_, candidate)| candidate.as_ref())
+            .collect()
+    }
+
+    // format, all integers u32 little-endian and strings as length + UTF-8:
+    // "AIX1", bucket count, then per bucket its key, entry count and each
+    // entry's lowercased and original spelling. buckets are written in key
+    // order so the same dictionary always saves to the same bytes
+    pub fn save<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        let mut buckets: Vec<_> = self.buckets.iter().collect();
+        buckets.sort_unstable_by_key(|(key, _)| *key);
+
+        writer.write_all(MAGIC)?;
+        write_len(&mut writer, buckets.len())?;
+        for (key, entries) in buckets {
+            write_str(&mut writer, &key.iter().collect::<String>())?;
+            write_len(&mut writer, entries.len())?;
+            for (lower_candidate, candidate) in entries {
+                write_str(&mut writer, lower_candidate)?;
+                write_str(&mut writer, candidate)?;
+            }
+        }
+        writer.flush()
+    }
+
+    // reads what save wrote; keys are taken as stored, nothing is re-sorted.
+    // a bad header or invalid UTF-8 is InvalidData, truncated input UnexpectedEof
+    pub fn load<R: Read>(mut reader: R) -> io::Result<AnagramIndex<'static>> {
+        let mut magic = [0u8; 4];
+        reader.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            return Err(invalid_data("not a saved AnagramIndex"));
+        }
+
+        let bucket_count = read_len(&mut reader)?;
+        let mut buckets = HashMap::new();
+        for _ in 0..bucket_count {
+            let key: Vec<char> = read_string(&mut reader)?.chars().collect();
+            let entry_count = read_len(&mut reader)?;
+            let mut entries = Vec::new();
+            for _ in 0..entry_count {
+                let lower_candidate = read_string(&mut reader)?;
+                let candidate = read_string(&mut reader)?;
+                entries.push((lower_candidate, Cow::Owned(candidate)));
+            }
+            buckets.insert(key, entries);
+        }
+        Ok(AnagramIndex { buckets })
+    }
+}
+
+fn write_len<W: Write>(writer: &mut W, len: usize) -> io::Result<()> {
+    let len = u32::try_from(len).map_err(|_| invalid_data("length does not fit in u32"))?;
+    writer.write_all(&len.to_le_bytes())
+}
+
+fn write_str<W: Write>(writer: &mut W, s: &str) -> io::Result<()> {
+    write_len(writer, s.len())?;
+    writer.write_all(s.as_bytes())
+}
+
+fn read_len<R: Read>(reader: &mut R) -> io::Result<usize> {
+    let mut bytes = [0u8; 4];
+    reader.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes) as usize)
+}
+
+fn read_string<R: Read>(reader: &mut R) -> io::Result<String> {
+    let len = read_len(reader)?;
+    // read through take so a corrupt length can't trigger a huge allocation
+    let mut bytes = Vec::new();
+    reader.take(len as u64).read_to_end(&mut bytes)?;
+    if bytes.len() != len {
+        return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+    }
+    String::from_utf8(bytes).map_err(|_| invalid_data("string is not UTF-8"))
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
@@ -0,0 +1,49 @@
+use std::collections::HashSet;
+
+use crate::anagram_v2::get_sorted;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Locale {
+    #[default]
+    Default,
+    // dotted and dotless i are separate letters: I <-> ı, İ <-> i
+    Turkish,
+}
+
+pub fn anagrams_for_locale<'a>(
+    word: &str,
+    possible_anagrams: &[&'a str],
+    locale: Locale,
+) -> HashSet<&'a str> {
+    if locale == Locale::Default {
+        return crate::anagram_v2::anagrams_for(word, possible_anagrams);
+    }
+    let folded_word = fold(word, locale);
+    let word_sorted = get_sorted(&folded_word);
+    possible_anagrams
+        .iter()
+        .filter(|anagram_candidate| {
+            // "I" and "ı" differ in bytes, so the raw length check can't be reused
+            let folded_candidate = fold(anagram_candidate, locale);
+            folded_candidate != folded_word && word_sorted == get_sorted(&folded_candidate)
+        })
+        .copied()
+        .collect()
+}
+
+fn fold(word: &str, locale: Locale) -> String {
+    match locale {
+        Locale::Default => word.to_lowercase(),
+        Locale::Turkish => {
+            let mut folded = String::with_capacity(word.len());
+            for c in word.chars() {
+                match c {
+                    'I' => folded.push('ı'),
+                    'İ' => folded.push('i'),
+                    _ => folded.extend(c.to_lowercase()),
+                }
+            }
+            folded
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use std::collections::HashMap;
+
+use crate::anagram_v3::{fits, get_counts};
+
+// combinations can explode on big dictionaries, so output is capped
+pub const MULTIWORD_RESULT_LIMIT: usize = 1000;
+
+// finds up to max_words dictionary words whose letters together are exactly the
+// letters of `word` (case-insensitive, whitespace ignored in `word` and in every
+// entry). Words may repeat and each combination is returned once, in dictionary
+// order, with entries as given; `word` alone is excluded
+pub fn multiword_anagrams<'a>(word: &str, dict: &[&'a str], max_words: usize) -> Vec<Vec<&'a str>> {
+    let lower_word = letters(word);
+    let mut remaining = get_counts(&lower_word);
+    // pruning: drop every dictionary word that can't fit in the target at all
+    let entries: Vec<(&'a str, HashMap<char, usize>)> = dict
+        .iter()
+        .map(|&entry| (entry, letters(entry)))
+        .filter(|(_, lower_entry)| !lower_entry.is_empty())
+        .map(|(entry, lower_entry)| (entry, get_counts(&lower_entry)))
+        .filter(|(_, counts)| fits(counts, &remaining))
+        .collect();
+
+    let mut results = Vec::new();
+    let mut current = Vec::new();
+    search(
+        &entries,
+        0,
+        max_words,
+        &mut remaining,
+        &mut current,
+        &mut results,
+    );
+    results.retain(|combination: &Vec<&str>| {
+        !(combination.len() == 1 && letters(combination[0]) == lower_word)
+    });
+    results
+}
+
+// lowercased with all whitespace removed, the form both sides are compared in
+fn letters(text: &str) -> String {
+    text.to_lowercase().split_whitespace().collect()
+}
+
+fn search<'a>(
+    entries: &[(&'a str, HashMap<char, usize>)],
+    start: usize,
+    words_left: usize,
+    remaining: &mut HashMap<char, usize>,
+    current: &mut Vec<&'a str>,
+    results: &mut Vec<Vec<&'a str>>,
+) {
+    if remaining.values().all(|&count| count == 0) {
+        if !current.is_empty() {
+            results.push(current.clone());
+        }
+        return;
+    }
+    if words_left == 0 {
+        return;
+    }
+    for (index, (entry, counts)) in entries.iter().enumerate().skip(start) {
+        if results.len() >= MULTIWORD_RESULT_LIMIT {
+            return;
+        }
+        if !fits(counts, remaining) {
+            continue;
+        }
+        // subtract, recurse from the same index so words can repeat, then restore
+        for (c, count) in counts {
+            *remaining.get_mut(c).unwrap() -= count;
+        }
+        current.push(entry);
+        search(entries, index, words_left - 1, remaining, current, results);
+        current.pop();
+        for (c, count) in counts {
+            *remaining.get_mut(c).unwrap() += count;
+        }
+    }
+}
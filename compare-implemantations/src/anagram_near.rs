@@ -0,0 +1,37 @@
+use std::collections::HashMap;
+
+use crate::anagram_v3::get_counts;
+
+// candidates whose letter multiset is within max_diff of the word's, measured
+// as the L1 distance between the two frequency counts: 0 is an exact anagram,
+// adding or removing one letter is 1, and swapping one letter for another is 2
+// (one removed, one added). Case-insensitive like anagrams_for, and the word
+// itself is still excluded. Matches come back in input order, duplicates kept
+pub fn near_anagrams<'a>(word: &str, candidates: &[&'a str], max_diff: usize) -> Vec<&'a str> {
+    let lower_word = word.to_lowercase();
+    let word_counts = get_counts(&lower_word);
+    let word_chars = lower_word.chars().count();
+    candidates
+        .iter()
+        .filter(|candidate| {
+            let lower_candidate = candidate.to_lowercase();
+            // every extra or missing char adds at least one to the distance
+            lower_candidate.chars().count().abs_diff(word_chars) <= max_diff
+                && lower_candidate != lower_word
+                && count_distance(&word_counts, &get_counts(&lower_candidate)) <= max_diff
+        })
+        .copied()
+        .collect()
+}
+
+fn count_distance(a: &HashMap<char, usize>, b: &HashMap<char, usize>) -> usize {
+    let only_in_b: usize = b
+        .iter()
+        .filter(|(c, _)| !a.contains_key(c))
+        .map(|(_, count)| count)
+        .sum();
+    a.iter()
+        .map(|(c, count)| count.abs_diff(b.get(c).copied().unwrap_or(0)))
+        .sum::<usize>()
+        + only_in_b
+}
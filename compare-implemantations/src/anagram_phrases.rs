@@ -0,0 +1,27 @@
+use std::collections::HashSet;
+
+use crate::anagram_v2::get_sorted;
+
+// "Dirty room!" and "dormitory" normalize to "dirtyroom" and "dormitory";
+// the self-match check uses the normalized form too
+pub fn anagrams_for_phrases<'a>(phrase: &str, possible_anagrams: &[&'a str]) -> HashSet<&'a str> {
+    let normalized_phrase = normalize(phrase);
+    let phrase_sorted = get_sorted(&normalized_phrase);
+    possible_anagrams
+        .iter()
+        .filter(|anagram_candidate| {
+            let normalized_candidate = normalize(anagram_candidate);
+            normalized_candidate != normalized_phrase
+                && phrase_sorted == get_sorted(&normalized_candidate)
+        })
+        .copied()
+        .collect()
+}
+
+fn normalize(phrase: &str) -> String {
+    phrase
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
@@ -0,0 +1,22 @@
+use std::io::{self, BufRead};
+
+use crate::anagram_v2::anagram_matcher;
+
+// one line buffer is reused for the whole read, so memory stays at the
+// matches plus the longest line; "\n" / "\r\n" endings are trimmed and
+// blank lines skipped
+pub fn anagrams_from_reader<R: BufRead>(word: &str, mut reader: R) -> io::Result<Vec<String>> {
+    let is_anagram = anagram_matcher(word, false);
+    let mut anagrams = Vec::new();
+    let mut line = String::new();
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            return Ok(anagrams);
+        }
+        let anagram_candidate = line.trim_end_matches(['\n', '\r']);
+        if !anagram_candidate.trim().is_empty() && is_anagram(anagram_candidate) {
+            anagrams.push(anagram_candidate.to_string());
+        }
+    }
+}
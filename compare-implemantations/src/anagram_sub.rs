@@ -0,0 +1,19 @@
+use std::collections::HashSet;
+
+use crate::anagram_v3::{fits, get_counts};
+
+// Scrabble-style "can be spelled from": each candidate letter is used at most as
+// often as it appears in `letters`. Unlike anagrams_for, the letters themselves
+// (and the empty string) count as a match
+pub fn sub_anagrams<'a>(letters: &str, candidates: &[&'a str]) -> HashSet<&'a str> {
+    let available = get_counts(&letters.to_lowercase());
+    candidates
+        .iter()
+        .filter(|candidate| {
+            // a longer candidate can never fit
+            candidate.chars().count() <= letters.chars().count()
+                && fits(&get_counts(&candidate.to_lowercase()), &available)
+        })
+        .copied()
+        .collect()
+}
@@ -0,0 +1,55 @@
+use std::collections::HashSet;
+use unicode_normalization::UnicodeNormalization;
+use unicode_normalization::char::is_combining_mark;
+
+pub fn anagrams_for<'a>(word: &str, possible_anagrams: &[&'a str]) -> HashSet<&'a str> {
+    let normalized_word = normalize(word);
+    let word_sorted = get_sorted(&normalized_word);
+    possible_anagrams
+        .iter()
+        .filter(|anagram_candidate| {
+            // byte length depends on the encoding, so no cheap length check here
+            let normalized_candidate = normalize(anagram_candidate);
+            normalized_candidate != normalized_word
+                && word_sorted == get_sorted(&normalized_candidate)
+        })
+        .copied()
+        .collect()
+}
+
+// opt-in accent-insensitive matching: "naïve" matches "avine". Words are lowercased,
+// decomposed to NFD, and every General_Category=Mark char (Mn, Mc, Me) is dropped.
+// The self-match check uses the folded form, so "resume" excludes "résumé"
+pub fn anagrams_for_ascii_folded<'a>(
+    word: &str,
+    possible_anagrams: &[&'a str],
+) -> HashSet<&'a str> {
+    let folded_word = fold_diacritics(word);
+    let word_sorted = get_sorted(&folded_word);
+    possible_anagrams
+        .iter()
+        .filter(|anagram_candidate| {
+            let folded_candidate = fold_diacritics(anagram_candidate);
+            folded_candidate != folded_word && word_sorted == get_sorted(&folded_candidate)
+        })
+        .copied()
+        .collect()
+}
+
+fn fold_diacritics(word: &str) -> String {
+    word.to_lowercase()
+        .nfd()
+        .filter(|&c| !is_combining_mark(c))
+        .collect()
+}
+
+// NFC keeps "é" as one char; NFD would split off the accent and let it move between letters
+fn normalize(word: &str) -> String {
+    word.to_lowercase().nfc().collect()
+}
+
+fn get_sorted(word: &str) -> Vec<char> {
+    let mut word_sorted: Vec<char> = word.chars().collect();
+    word_sorted.sort_unstable();
+    word_sorted
+}
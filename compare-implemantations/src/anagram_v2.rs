@@ -1,27 +1,156 @@
 use std::collections::HashSet;
 
+// an empty word only passes the length check for empty candidates, which the
+// self-exclusion then drops, so anagrams_for("", ..) is always empty; every
+// implementation follows the same rule
 pub fn anagrams_for<'a>(word: &str, possible_anagrams: &[&'a str]) -> HashSet<&'a str> {
-    let lower_word = word.to_lowercase();
-    let word_sorted = get_sorted(&lower_word);
-    // asign once
-    let word_length = word.len();
+    filter_anagrams(word, possible_anagrams).collect()
+}
+
+// keeps the order of possible_anagrams, duplicates included
+pub fn anagrams_for_sorted<'a>(word: &str, possible_anagrams: &[&'a str]) -> Vec<&'a str> {
+    filter_anagrams(word, possible_anagrams).collect()
+}
+
+pub fn anagrams_for_alphabetical<'a>(word: &str, possible_anagrams: &[&'a str]) -> Vec<&'a str> {
+    let mut anagrams = anagrams_for_sorted(word, possible_anagrams);
+    anagrams.sort_unstable();
+    anagrams
+}
+
+// longest first by char count, then alphabetical (byte order) within a
+// length; duplicates are kept like in anagrams_for_sorted. matches share the
+// word's byte length, so the length key only separates words whose chars
+// take different numbers of bytes
+pub fn anagrams_for_ranked<'a>(word: &str, possible_anagrams: &[&'a str]) -> Vec<&'a str> {
+    let mut anagrams = anagrams_for_sorted(word, possible_anagrams);
+    anagrams.sort_unstable_by(|a, b| {
+        b.chars()
+            .count()
+            .cmp(&a.chars().count())
+            .then_with(|| a.cmp(b))
+    });
+    anagrams
+}
+
+// same filter as anagrams_for, counting distinct matches so a repeated
+// candidate counts once: always equal to anagrams_for(..).len(). nothing is
+// collected, a match only counts if no earlier candidate is the same &str,
+// so the extra scan over the prefix runs for matches only
+pub fn count_anagrams(word: &str, possible_anagrams: &[&str]) -> usize {
+    let is_anagram = anagram_matcher(word, false);
     possible_anagrams
         .iter()
-        .filter(|anagram_candidate| {
-            // first, we check the more cheap one
-            if anagram_candidate.len() != word_length {
-                return false;
-            }
-            // This statment will be avoided if we get true on the prev check
-            let lower_anagram_candidate = anagram_candidate.to_lowercase();
-            lower_anagram_candidate != lower_word
-                && word_sorted == get_sorted(&lower_anagram_candidate)
+        .enumerate()
+        .filter(|&(index, anagram_candidate)| {
+            is_anagram(anagram_candidate) && !possible_anagrams[..index].contains(anagram_candidate)
         })
+        .count()
+}
+
+// include_self keeps candidates equal to word ignoring case; the set holds
+// each distinct &str once, so repeated candidates collapse while "stop" and
+// "STOP" stay two entries. false is the same as anagrams_for
+pub fn anagrams_for_opts<'a>(
+    word: &str,
+    possible_anagrams: &[&'a str],
+    include_self: bool,
+) -> HashSet<&'a str> {
+    let is_anagram = anagram_matcher(word, include_self);
+    possible_anagrams
+        .iter()
         .copied()
+        .filter(|anagram_candidate| is_anagram(anagram_candidate))
+        .collect()
+}
+
+// ascending index order, so results map straight back to parallel arrays
+pub fn anagrams_for_indexed<'a>(
+    word: &str,
+    possible_anagrams: &[&'a str],
+) -> Vec<(usize, &'a str)> {
+    let is_anagram = anagram_matcher(word, false);
+    possible_anagrams
+        .iter()
+        .copied()
+        .enumerate()
+        .filter(|(_, anagram_candidate)| is_anagram(anagram_candidate))
+        .collect()
+}
+
+// works on Vec<String>, Cow<str>, ... without reborrowing; references point
+// into possible_anagrams, in input order with duplicates included
+pub fn anagrams_for_generic<'a, S: AsRef<str>>(
+    word: &str,
+    possible_anagrams: &'a [S],
+) -> Vec<&'a S> {
+    let is_anagram = anagram_matcher(word, false);
+    possible_anagrams
+        .iter()
+        .filter(|anagram_candidate| is_anagram(anagram_candidate.as_ref()))
+        .collect()
+}
+
+// any source of borrowed candidates, e.g. text.lines() or a map's keys,
+// consumed lazily without collecting into a slice first
+pub fn anagrams_for_iter<'a, I: IntoIterator<Item = &'a str>>(
+    word: &str,
+    possible_anagrams: I,
+) -> HashSet<&'a str> {
+    let is_anagram = anagram_matcher(word, false);
+    possible_anagrams
+        .into_iter()
+        .filter(|anagram_candidate| is_anagram(anagram_candidate))
         .collect()
 }
 
-fn get_sorted(word: &str) -> Vec<char> {
+fn filter_anagrams<'a, 'b>(
+    word: &str,
+    possible_anagrams: &'b [&'a str],
+) -> impl Iterator<Item = &'a str> + 'b {
+    let is_anagram = anagram_matcher(word, false);
+    possible_anagrams
+        .iter()
+        .copied()
+        .filter(move |anagram_candidate| is_anagram(anagram_candidate))
+}
+
+pub(crate) fn anagram_matcher(word: &str, include_self: bool) -> impl Fn(&str) -> bool + use<> {
+    let lower_word = word.to_lowercase();
+    let word_key = anagram_key(word);
+    // asign once
+    let word_length = word.len();
+    move |anagram_candidate| {
+        // first, we check the more cheap one
+        if anagram_candidate.len() != word_length {
+            return false;
+        }
+        // This statment will be avoided if we get true on the prev check
+        let lower_anagram_candidate = anagram_candidate.to_lowercase();
+        (include_self || lower_anagram_candidate != lower_word)
+            && word_key == sorted_key(&lower_anagram_candidate)
+    }
+}
+
+// same test the anagrams_for filter applies to each candidate: equal byte
+// length, different words ignoring case, equal keys
+pub fn is_anagram(a: &str, b: &str) -> bool {
+    a.len() == b.len() && a.to_lowercase() != b.to_lowercase() && anagram_key(a) == anagram_key(b)
+}
+
+/// Canonical form for anagram equality: the lowercased word with its chars
+/// sorted. Two words are anagrams (ignoring case) exactly when their keys are
+/// equal, so the owned `String` can be used directly as a `HashMap` key.
+pub fn anagram_key(word: &str) -> String {
+    sorted_key(&word.to_lowercase())
+}
+
+// for callers that already hold the lowercased word
+fn sorted_key(lower_word: &str) -> String {
+    get_sorted(lower_word).into_iter().collect()
+}
+
+pub(crate) fn get_sorted(word: &str) -> Vec<char> {
     let mut word_sorted: Vec<char> = word.chars().collect();
     word_sorted.sort_unstable();
     word_sorted
@@ -0,0 +1,35 @@
+use std::collections::{HashMap, HashSet};
+
+pub fn anagrams_for<'a>(word: &str, possible_anagrams: &[&'a str]) -> HashSet<&'a str> {
+    let lower_word = word.to_lowercase();
+    // count each char once, O(n) instead of sorting
+    let word_counts = get_counts(&lower_word);
+    let word_length = word.len();
+    possible_anagrams
+        .iter()
+        .filter(|anagram_candidate| {
+            if anagram_candidate.len() != word_length {
+                return false;
+            }
+            let lower_anagram_candidate = anagram_candidate.to_lowercase();
+            lower_anagram_candidate != lower_word
+                && word_counts == get_counts(&lower_anagram_candidate)
+        })
+        .copied()
+        .collect()
+}
+
+pub(crate) fn get_counts(word: &str) -> HashMap<char, usize> {
+    let mut counts = HashMap::new();
+    for c in word.chars() {
+        *counts.entry(c).or_insert(0) += 1;
+    }
+    counts
+}
+
+// every letter of counts is available in remaining (multiset containment)
+pub(crate) fn fits(counts: &HashMap<char, usize>, remaining: &HashMap<char, usize>) -> bool {
+    counts
+        .iter()
+        .all(|(c, count)| remaining.get(c).is_some_and(|left| left >= count))
+}
@@ -0,0 +1,57 @@
+// small tool over both exercises:
+//   playground [annotate]    < board.txt   prints the annotated minesweeper board
+//   playground anagram WORD  < words.txt   prints the candidates that are anagrams of WORD
+// empty stdin prints nothing and exits successfully on both paths
+use std::env;
+use std::io::{self, BufWriter, Read, Write};
+use std::process::ExitCode;
+
+use rust_playground::anagrams_from_reader;
+
+const USAGE: &str = "usage: playground [annotate] < board | playground anagram <word> < words";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let result = match args.as_slice() {
+        [] | ["annotate"] => annotate(),
+        ["anagram", word] => anagram(word),
+        _ => {
+            eprintln!("{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("error: {message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn annotate() -> Result<(), String> {
+    let mut board = String::new();
+    io::stdin()
+        .read_to_string(&mut board)
+        .map_err(|err| err.to_string())?;
+    // lines() also drops a trailing "\r", so boards saved on Windows work
+    let rows: Vec<&str> = board.lines().collect();
+    let annotated = minesweeper::try_annotate(&rows).map_err(|err| err.to_string())?;
+
+    let mut out = BufWriter::new(io::stdout().lock());
+    for row in annotated {
+        writeln!(out, "{row}").map_err(|err| err.to_string())?;
+    }
+    out.flush().map_err(|err| err.to_string())
+}
+
+fn anagram(word: &str) -> Result<(), String> {
+    let anagrams = anagrams_from_reader(word, io::stdin().lock()).map_err(|err| err.to_string())?;
+
+    let mut out = BufWriter::new(io::stdout().lock());
+    for anagram in anagrams {
+        writeln!(out, "{anagram}").map_err(|err| err.to_string())?;
+    }
+    out.flush().map_err(|err| err.to_string())
+}
@@ -0,0 +1,160 @@
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::fmt;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+// lets the harness treat every implementation the same way, e.g. `[&V1, &V2, &V3, &V4]`
+pub trait AnagramFinder {
+    fn name(&self) -> &'static str;
+    fn find<'a>(&self, word: &str, candidates: &[&'a str]) -> HashSet<&'a str>;
+}
+
+#[cfg(feature = "impl-v1")]
+pub struct V1;
+pub struct V2;
+pub struct V3;
+pub struct V4;
+#[cfg(feature = "impl-ascii")]
+pub struct Ascii;
+pub struct Auto;
+
+#[cfg(feature = "impl-v1")]
+impl AnagramFinder for V1 {
+    fn name(&self) -> &'static str {
+        "v1"
+    }
+
+    fn find<'a>(&self, word: &str, candidates: &[&'a str]) -> HashSet<&'a str> {
+        crate::anagram::anagrams_for(word, candidates)
+    }
+}
+
+impl AnagramFinder for V2 {
+    fn name(&self) -> &'static str {
+        "v2"
+    }
+
+    fn find<'a>(&self, word: &str, candidates: &[&'a str]) -> HashSet<&'a str> {
+        crate::anagram_v2::anagrams_for(word, candidates)
+    }
+}
+
+impl AnagramFinder for V3 {
+    fn name(&self) -> &'static str {
+        "v3"
+    }
+
+    fn find<'a>(&self, word: &str, candidates: &[&'a str]) -> HashSet<&'a str> {
+        crate::anagram_v3::anagrams_for(word, candidates)
+    }
+}
+
+impl AnagramFinder for V4 {
+    fn name(&self) -> &'static str {
+        "v4"
+    }
+
+    fn find<'a>(&self, word: &str, candidates: &[&'a str]) -> HashSet<&'a str> {
+        crate::anagram_v4::anagrams_for(word, candidates)
+    }
+}
+
+#[cfg(feature = "impl-ascii")]
+impl AnagramFinder for Ascii {
+    fn name(&self) -> &'static str {
+        "ascii"
+    }
+
+    fn find<'a>(&self, word: &str, candidates: &[&'a str]) -> HashSet<&'a str> {
+        crate::anagram_ascii::anagrams_for_ascii(word, candidates)
+    }
+}
+
+impl AnagramFinder for Auto {
+    fn name(&self) -> &'static str {
+        "auto"
+    }
+
+    fn find<'a>(&self, word: &str, candidates: &[&'a str]) -> HashSet<&'a str> {
+        crate::anagram_auto::anagrams_for_auto(word, candidates)
+    }
+}
+
+// only the implementations compiled in; impl-v1 and impl-ascii are default features
+pub const FINDERS: &[&dyn AnagramFinder] = &[
+    #[cfg(feature = "impl-v1")]
+    &V1,
+    &V2,
+    &V3,
+    &V4,
+    #[cfg(feature = "impl-ascii")]
+    &Ascii,
+    &Auto,
+];
+
+// quick in-process timing of every FINDERS entry, for examples and ad-hoc
+// checks rather than criterion runs: one untimed warm-up call, then the median
+// (upper middle for even counts) of `iterations` timed calls
+pub fn bench_compare(
+    word: &str,
+    candidates: &[&str],
+    iterations: usize,
+) -> BTreeMap<&'static str, Duration> {
+    assert!(iterations > 0, "bench_compare needs at least one iteration");
+    FINDERS
+        .iter()
+        .map(|finder| {
+            black_box(finder.find(black_box(word), black_box(candidates)));
+            let mut samples: Vec<Duration> = (0..iterations)
+                .map(|_| {
+                    let start = Instant::now();
+                    black_box(finder.find(black_box(word), black_box(candidates)));
+                    start.elapsed()
+                })
+                .collect();
+            samples.sort_unstable();
+            (finder.name(), samples[iterations / 2])
+        })
+        .collect()
+}
+
+// two finders that returned different sets, and the words only one of them found
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Divergence {
+    pub reference: &'static str,
+    pub other: &'static str,
+    pub symmetric_difference: BTreeSet<String>,
+}
+
+impl fmt::Display for Divergence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} and {} disagree on {:?}",
+            self.reference, self.other, self.symmetric_difference
+        )
+    }
+}
+
+impl std::error::Error for Divergence {}
+
+// checks every finder against the first one (v1, or v2 without impl-v1);
+// reports the first that differs
+pub fn assert_agree(word: &str, candidates: &[&str]) -> Result<(), Divergence> {
+    let (reference, others) = FINDERS.split_first().unwrap();
+    let expected = reference.find(word, candidates);
+    for finder in others {
+        let found = finder.find(word, candidates);
+        if found != expected {
+            return Err(Divergence {
+                reference: reference.name(),
+                other: finder.name(),
+                symmetric_difference: expected
+                    .symmetric_difference(&found)
+                    .map(|anagram| anagram.to_string())
+                    .collect(),
+            });
+        }
+    }
+    Ok(())
+}
@@ -1,9 +1,65 @@
+#[cfg(feature = "impl-v1")]
 pub mod anagram;
+#[cfg(feature = "impl-ascii")]
+pub mod anagram_ascii;
+pub mod anagram_auto;
+pub mod anagram_groups;
+pub mod anagram_index;
+pub mod anagram_locale;
+pub mod anagram_multiword;
+pub mod anagram_near;
+pub mod anagram_phrases;
+pub mod anagram_reader;
+pub mod anagram_sub;
 pub mod anagram_v2;
+pub mod anagram_v3;
+pub mod anagram_v4;
+pub mod finder;
+#[cfg(feature = "graphemes")]
+pub mod anagram_graphemes;
+#[cfg(feature = "rayon")]
+pub mod anagram_parallel;
+#[cfg(feature = "unicode")]
+pub mod anagram_unicode;
 
 // Re-export the functions with descriptive names
+#[cfg(feature = "impl-v1")]
 pub use anagram::anagrams_for as anagrams_for_v1;
 pub use anagram_v2::anagrams_for as anagrams_for_v2;
+pub use anagram_v3::anagrams_for as anagrams_for_v3;
+pub use anagram_v4::anagrams_for as anagrams_for_v4;
+#[cfg(feature = "graphemes")]
+pub use anagram_graphemes::anagrams_for_graphemes;
+#[cfg(feature = "rayon")]
+pub use anagram_parallel::{anagrams_for_parallel, group_anagrams_parallel};
+#[cfg(feature = "unicode")]
+pub use anagram_unicode::anagrams_for as anagrams_for_unicode;
+#[cfg(feature = "unicode")]
+pub use anagram_unicode::anagrams_for_ascii_folded;
+#[cfg(feature = "impl-ascii")]
+pub use anagram_ascii::{anagrams_for_ascii, ascii_anagram_key};
+pub use anagram_auto::anagrams_for_auto;
+pub use anagram_groups::{anagram_histogram, group_anagrams, largest_anagram_group};
+pub use anagram_index::AnagramIndex;
+pub use anagram_locale::{Locale, anagrams_for_locale};
+pub use anagram_multiword::{MULTIWORD_RESULT_LIMIT, multiword_anagrams};
+pub use anagram_near::near_anagrams;
+pub use anagram_phrases::anagrams_for_phrases;
+pub use anagram_reader::anagrams_from_reader;
+pub use anagram_sub::sub_anagrams;
+pub use anagram_v4::LengthBuckets;
+pub use anagram_v2::{
+    anagram_key, anagrams_for_alphabetical, anagrams_for_generic, anagrams_for_indexed,
+    anagrams_for_iter, anagrams_for_opts, anagrams_for_ranked, anagrams_for_sorted, count_anagrams,
+    is_anagram,
+};
+pub use finder::{
+    AnagramFinder, Auto, Divergence, FINDERS, V2, V3, V4, assert_agree, bench_compare,
+};
+#[cfg(feature = "impl-ascii")]
+pub use finder::Ascii;
+#[cfg(feature = "impl-v1")]
+pub use finder::V1;
 
 // You can also provide a default implementation
 pub use anagram_v2::anagrams_for as anagrams_for;
\ No newline at end of file
@@ -0,0 +1,48 @@
+use proptest::prelude::*;
+use rust_playground::{
+    AnagramIndex, anagrams_for, anagrams_for_iter, assert_agree, count_anagrams,
+};
+
+// small alphabet with case and non-ASCII letters so collisions and Unicode case rules both show up
+const ALPHABET: &str = "[abcABCéÉßİıkK\u{212A}]";
+
+fn word_and_candidates() -> impl Strategy<Value = (String, Vec<String>)> {
+    proptest::string::string_regex(&format!("{ALPHABET}{{0,6}}"))
+        .unwrap()
+        .prop_flat_map(|word| {
+            let chars: Vec<char> = word.chars().collect();
+            let shuffled = Just(chars).prop_shuffle().prop_map(String::from_iter);
+            let random = proptest::string::string_regex(&format!("{ALPHABET}{{0,6}}")).unwrap();
+            let candidate = prop_oneof![shuffled, random];
+            (Just(word), prop::collection::vec(candidate, 0..12))
+        })
+}
+
+proptest! {
+    #[test]
+    fn all_versions_agree((word, candidates) in word_and_candidates()) {
+        let candidates: Vec<&str> = candidates.iter().map(String::as_str).collect();
+        prop_assert_eq!(assert_agree(&word, &candidates), Ok(()));
+    }
+
+    #[test]
+    fn index_agrees_with_anagrams_for((word, candidates) in word_and_candidates()) {
+        let candidates: Vec<&str> = candidates.iter().map(String::as_str).collect();
+        let index = AnagramIndex::build(&candidates);
+        prop_assert_eq!(index.matches(&word), anagrams_for(&word, &candidates));
+    }
+
+    #[test]
+    fn iter_agrees_with_anagrams_for((word, candidates) in word_and_candidates()) {
+        let slice: Vec<&str> = candidates.iter().map(String::as_str).collect();
+        let lazy = anagrams_for_iter(&word, candidates.iter().map(String::as_str));
+        prop_assert_eq!(lazy, anagrams_for(&word, &slice));
+    }
+
+    #[test]
+    fn count_agrees_with_anagrams_for((word, candidates) in word_and_candidates()) {
+        // every candidate twice, so repeated matches are always exercised
+        let candidates: Vec<&str> = candidates.iter().chain(&candidates).map(String::as_str).collect();
+        prop_assert_eq!(count_anagrams(&word, &candidates), anagrams_for(&word, &candidates).len());
+    }
+}
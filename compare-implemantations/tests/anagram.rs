@@ -0,0 +1,811 @@
+use rust_playground::*;
+use std::collections::HashSet;
+
+fn set<'a>(words: &[&'a str]) -> HashSet<&'a str> {
+    words.iter().copied().collect()
+}
+
+#[test]
+fn detects_simple_anagrams() {
+    let candidates = ["tan", "stand", "at"];
+    for finder in FINDERS {
+        assert_eq!(
+            finder.find("ant", &candidates),
+            set(&["tan"]),
+            "{}",
+            finder.name()
+        );
+    }
+}
+
+#[test]
+fn is_case_insensitive_and_excludes_the_word_itself() {
+    let candidates = ["Listen", "Silent", "enlists", "tinsel", "LISTEN"];
+    for finder in FINDERS {
+        assert_eq!(
+            finder.find("listen", &candidates),
+            set(&["Silent", "tinsel"]),
+            "{}",
+            finder.name()
+        );
+    }
+}
+
+#[test]
+fn counts_repeated_letters() {
+    let candidates = ["banana", "bnanaa", "bananaa", "banan"];
+    for finder in FINDERS {
+        assert_eq!(
+            finder.find("abnana", &candidates),
+            set(&["banana", "bnanaa"]),
+            "{}",
+            finder.name()
+        );
+    }
+}
+
+#[cfg(feature = "unicode")]
+#[test]
+fn unicode_matches_across_precomposed_and_decomposed_encodings() {
+    let precomposed = "caf\u{e9}";
+    let decomposed = "cafe\u{301}";
+    let candidates = [
+        "\u{e9}fac",
+        "e\u{301}fac",
+        "\u{c9}FAC",
+        decomposed,
+        "c\u{e1}fe",
+    ];
+    assert_eq!(
+        anagrams_for_unicode(precomposed, &candidates),
+        set(&["\u{e9}fac", "e\u{301}fac", "\u{c9}FAC"])
+    );
+    assert_eq!(
+        anagrams_for_unicode(decomposed, &candidates),
+        set(&["\u{e9}fac", "e\u{301}fac", "\u{c9}FAC"])
+    );
+}
+
+#[cfg(feature = "graphemes")]
+#[test]
+fn graphemes_keep_combining_marks_and_flags_whole() {
+    // "e\u{301}" is one grapheme; moving the accent onto another letter is not an anagram
+    let word = "cafe\u{301}";
+    let candidates = ["e\u{301}fac", "ca\u{301}fe", "face\u{301}"];
+    assert_eq!(
+        anagrams_for_graphemes(word, &candidates),
+        set(&["e\u{301}fac", "face\u{301}"])
+    );
+    assert_eq!(anagrams_for_v2(word, &candidates).len(), 3);
+
+    // flags are two regional indicators; swapping them yields a different flag
+    let flags = "\u{1f1fa}\u{1f1f8}\u{1f1e8}\u{1f1e6}";
+    let candidates = [
+        "\u{1f1e8}\u{1f1e6}\u{1f1fa}\u{1f1f8}",
+        "\u{1f1f8}\u{1f1fa}\u{1f1e6}\u{1f1e8}",
+    ];
+    assert_eq!(
+        anagrams_for_graphemes(flags, &candidates),
+        set(&["\u{1f1e8}\u{1f1e6}\u{1f1fa}\u{1f1f8}"])
+    );
+}
+
+#[test]
+fn sorted_preserves_input_order_and_duplicates() {
+    let candidates = ["tops", "post", "stop", "spot", "post", "stops"];
+    assert_eq!(
+        anagrams_for_sorted("pots", &candidates),
+        vec!["tops", "post", "stop", "spot", "post"]
+    );
+    assert_eq!(
+        anagrams_for_alphabetical("pots", &candidates),
+        vec!["post", "post", "spot", "stop", "tops"]
+    );
+}
+
+#[test]
+fn groups_anagrams_case_insensitively() {
+    let words = ["stop", "cat", "Pots", "act", "dog", "tops"];
+    assert_eq!(
+        group_anagrams(&words, 2),
+        vec![vec!["stop", "Pots", "tops"], vec!["cat", "act"]]
+    );
+    assert_eq!(
+        group_anagrams(&words, 1),
+        vec![
+            vec!["stop", "Pots", "tops"],
+            vec!["cat", "act"],
+            vec!["dog"]
+        ]
+    );
+    assert!(group_anagrams(&[], 1).is_empty());
+}
+
+#[test]
+fn index_answers_repeated_queries_like_anagrams_for() {
+    let candidates = [
+        "enlists", "google", "inlets", "banana", "Silent", "listen", "tinsel",
+    ];
+    let index = AnagramIndex::build(&candidates);
+    for word in ["listen", "LISTEN", "inlets", "banana", "nothing"] {
+        assert_eq!(
+            index.matches(word),
+            anagrams_for(word, &candidates),
+            "{word}"
+        );
+    }
+}
+
+#[test]
+fn phrases_ignore_spaces_punctuation_and_case() {
+    let candidates = [
+        "Dirty room",
+        "dirty-room!",
+        "dormitory",
+        "Dormitory.",
+        "dirty rooms",
+    ];
+    assert_eq!(
+        anagrams_for_phrases("dormitory", &candidates),
+        set(&["Dirty room", "dirty-room!"])
+    );
+    // normalized forms are equal, so the phrase itself is excluded
+    assert_eq!(
+        anagrams_for_phrases("Dirty Room", &candidates),
+        set(&["dormitory", "Dormitory."])
+    );
+    assert_eq!(
+        anagrams_for_phrases("A gentleman", &["Elegant man!", "elegant men"]),
+        set(&["Elegant man!"])
+    );
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn parallel_matches_sequential() {
+    let words: Vec<String> = (0..5_000)
+        .map(|i| match i % 3 {
+            0 => "stop".to_string(),
+            1 => ["pots", "tops", "spot", "post"][i % 4].to_string(),
+            _ => format!("w{i}"),
+        })
+        .collect();
+    let candidates: Vec<&str> = words.iter().map(String::as_str).collect();
+    assert_eq!(
+        anagrams_for_parallel("stop", &candidates),
+        anagrams_for_v2("stop", &candidates)
+    );
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn parallel_grouping_matches_sequential() {
+    let words: Vec<String> = (0..20_000)
+        .map(|i| match i % 4 {
+            0 => ["stop", "pots", "Tops", "opts"][i / 4 % 4].to_string(),
+            1 => ["listen", "Silent", "enlist"][i % 3].to_string(),
+            _ => format!("w{}", i % 997),
+        })
+        .collect();
+    let words: Vec<&str> = words.iter().map(String::as_str).collect();
+    assert_eq!(group_anagrams_parallel(&words), group_anagrams(&words, 1));
+    assert!(group_anagrams_parallel(&[]).is_empty());
+}
+
+#[cfg(all(feature = "impl-v1", feature = "impl-ascii"))]
+#[test]
+fn finders_agree_with_free_functions_and_each_other() {
+    let candidates = ["stop", "pots", "Tops", "opts", "spots", "STOP", "post"];
+    for word in ["stop", "POST", "spots", "x"] {
+        let expected = anagrams_for_v1(word, &candidates);
+        assert_eq!(anagrams_for_v2(word, &candidates), expected);
+        assert_eq!(anagrams_for_v3(word, &candidates), expected);
+        assert_eq!(anagrams_for_v4(word, &candidates), expected);
+        assert_eq!(anagrams_for_ascii(word, &candidates), expected);
+        assert_eq!(anagrams_for_auto(word, &candidates), expected);
+        for finder in [&V1 as &dyn AnagramFinder, &V2, &V3, &V4, &Ascii, &Auto] {
+            assert_eq!(
+                finder.find(word, &candidates),
+                expected,
+                "{}",
+                finder.name()
+            );
+        }
+    }
+}
+
+#[test]
+fn turkish_locale_pairs_dotted_and_dotless_i() {
+    let candidates = ["ıks", "iks", "KİS", "kıs"];
+    // "I" folds to "ı" under Turkish rules, to "i" by default
+    assert_eq!(
+        anagrams_for_locale("SKI", &candidates, Locale::Turkish),
+        set(&["ıks", "kıs"])
+    );
+    assert_eq!(
+        anagrams_for_locale("skİ", &candidates, Locale::Turkish),
+        set(&["iks", "KİS"])
+    );
+}
+
+#[test]
+fn default_locale_matches_anagrams_for() {
+    let candidates = ["ıks", "iks", "KIS", "kis", "SKI"];
+    for word in ["ski", "SKI", "skİ"] {
+        assert_eq!(
+            anagrams_for_locale(word, &candidates, Locale::Default),
+            anagrams_for(word, &candidates)
+        );
+    }
+}
+
+#[cfg(feature = "unicode")]
+#[test]
+fn ascii_folded_ignores_diacritics_but_strict_does_not() {
+    let candidates = ["avine", "av\u{ef}ne", "Ave\u{301}in", "naive"];
+    assert_eq!(
+        anagrams_for_ascii_folded("na\u{ef}ve", &candidates),
+        set(&["avine", "av\u{ef}ne", "Ave\u{301}in"])
+    );
+    assert!(anagrams_for_unicode("na\u{ef}ve", &["avine"]).is_empty());
+    // folds to the same word, so it's excluded like any self-match
+    assert!(anagrams_for_ascii_folded("resume", &["r\u{e9}sum\u{e9}"]).is_empty());
+}
+
+#[test]
+fn multiword_finds_word_combinations() {
+    let dict = [
+        "dirty",
+        "room",
+        "dormitory",
+        "rot",
+        "diry",
+        "my",
+        "Dirt",
+        "or",
+        "y",
+        "moo",
+    ];
+    let pairs = multiword_anagrams("dormitory", &dict, 2);
+    assert!(pairs.contains(&vec!["dirty", "room"]));
+    assert!(
+        !pairs
+            .iter()
+            .any(|combination| combination == &vec!["dormitory"])
+    );
+    assert!(pairs.iter().all(|combination| combination.len() <= 2));
+
+    let triples = multiword_anagrams("dormitory", &dict, 3);
+    assert!(triples.contains(&vec!["room", "Dirt", "y"]));
+    assert!(triples.len() > pairs.len());
+}
+
+#[test]
+fn multiword_allows_repeats_and_handles_empty_input() {
+    assert_eq!(
+        multiword_anagrams("abab", &["ab", "ba"], 2),
+        vec![vec!["ab", "ab"], vec!["ab", "ba"], vec!["ba", "ba"]]
+    );
+    assert!(multiword_anagrams("abc", &[], 3).is_empty());
+    assert!(multiword_anagrams("abc", &["ab", "c"], 0).is_empty());
+}
+
+#[test]
+fn multiword_ignores_whitespace_in_entries() {
+    assert_eq!(
+        multiword_anagrams("stop it", &["to p", " s", "it", "pots"], 3),
+        vec![vec!["to p", " s", "it"], vec!["it", "pots"]]
+    );
+    // an entry that is only whitespace never fills a slot
+    assert_eq!(
+        multiword_anagrams("ab", &["  ", "a", "b"], 2),
+        vec![vec!["a", "b"]]
+    );
+    // spacing alone does not make the word its own anagram
+    assert!(multiword_anagrams("to p", &["top "], 1).is_empty());
+}
+
+#[test]
+fn sub_anagrams_respect_letter_counts_and_case() {
+    let candidates = ["tea", "Eat", "seat", "tease", "tee", "state", "a", "teas"];
+    assert_eq!(
+        sub_anagrams("SEAT", &candidates),
+        set(&["tea", "Eat", "seat", "a", "teas"])
+    );
+    assert_eq!(
+        sub_anagrams("settee", &["tee", "tees", "teetee"]),
+        set(&["tee", "tees"])
+    );
+}
+
+#[test]
+fn iter_accepts_lazy_sources() {
+    let text = "pots\nspot\nStop\nstops\nopts\n";
+    assert_eq!(
+        anagrams_for_iter("stop", text.lines()),
+        set(&["pots", "spot", "opts"])
+    );
+    let counts: std::collections::BTreeMap<&str, usize> = [("tops", 1), ("post", 2)].into();
+    assert_eq!(
+        anagrams_for_iter("spot", counts.keys().copied()),
+        set(&["tops", "post"])
+    );
+    assert!(anagrams_for_iter("stop", std::iter::empty()).is_empty());
+}
+
+#[test]
+fn near_anagrams_measure_letter_count_distance() {
+    let candidates = [
+        "pots", "spots", "Stop", "sop", "step", "dog", "stoop", "tops", "pots",
+    ];
+    assert_eq!(
+        near_anagrams("stop", &candidates, 0),
+        vec!["pots", "tops", "pots"]
+    );
+    assert_eq!(
+        near_anagrams("stop", &candidates, 1),
+        vec!["pots", "spots", "sop", "stoop", "tops", "pots"]
+    );
+    // swapping o for e removes one letter and adds another
+    assert_eq!(
+        near_anagrams("stop", &candidates, 2),
+        vec!["pots", "spots", "sop", "step", "stoop", "tops", "pots"]
+    );
+    assert!(near_anagrams("stop", &[], 3).is_empty());
+}
+
+#[test]
+fn near_anagrams_at_zero_match_anagrams_for() {
+    let candidates = ["enlists", "google", "inlets", "banana", "Silent", "listen"];
+    let near: HashSet<&str> = near_anagrams("listen", &candidates, 0)
+        .into_iter()
+        .collect();
+    assert_eq!(near, anagrams_for("listen", &candidates));
+}
+
+#[test]
+fn indexed_returns_positions_in_ascending_order() {
+    let candidates = ["stop", "pots", "dog", "tops", "stop", "spot"];
+    assert_eq!(
+        anagrams_for_indexed("post", &candidates),
+        vec![
+            (0, "stop"),
+            (1, "pots"),
+            (3, "tops"),
+            (4, "stop"),
+            (5, "spot")
+        ]
+    );
+    assert!(anagrams_for_indexed("post", &[]).is_empty());
+}
+
+#[test]
+fn length_buckets_are_reusable_across_queries() {
+    let candidates = [
+        "a",
+        "stop",
+        "pots",
+        "listen",
+        "silent",
+        "internationalization",
+    ];
+    let buckets = LengthBuckets::build(&candidates);
+    for word in ["stop", "LISTEN", "z", "nothing-matches"] {
+        assert_eq!(
+            buckets.anagrams_for(word),
+            anagrams_for(word, &candidates),
+            "{word}"
+        );
+    }
+}
+
+#[test]
+fn anagram_key_is_sorted_and_lowercased() {
+    assert_eq!(anagram_key("Listen"), "eilnst");
+    assert_eq!(anagram_key("ΑΒΓ"), anagram_key("γβα"));
+    assert_eq!(anagram_key(""), "");
+    assert_eq!(anagram_key("stop"), anagram_key("POTS"));
+    assert_ne!(anagram_key("stop"), anagram_key("stoop"));
+}
+
+#[test]
+fn anagram_histogram_counts_classes_by_size() {
+    let words = [
+        "stop", "pots", "tops", "listen", "silent", "cat", "act", "dog",
+    ];
+    let histogram = anagram_histogram(&words);
+    assert_eq!(
+        histogram.into_iter().collect::<Vec<_>>(),
+        vec![(1, 1), (2, 2), (3, 1)]
+    );
+    assert!(anagram_histogram(&[]).is_empty());
+}
+
+#[test]
+fn largest_anagram_group_finds_biggest_class() {
+    let words = [
+        "listen", "opts", "silent", "post", "pots", "cat", "spot", "stop", "tops",
+    ];
+    assert_eq!(
+        largest_anagram_group(&words),
+        vec!["opts", "post", "pots", "spot", "stop", "tops"]
+    );
+}
+
+#[test]
+fn largest_anagram_group_ties_go_to_first_class() {
+    assert_eq!(
+        largest_anagram_group(&["dog", "cat", "act", "god"]),
+        vec!["dog", "god"]
+    );
+    assert!(largest_anagram_group(&[]).is_empty());
+}
+
+#[test]
+fn is_anagram_matches_the_anagrams_for_filter() {
+    assert!(is_anagram("listen", "Silent"));
+    assert!(!is_anagram("listen", "listen"));
+    assert!(!is_anagram("Listen", "LISTEN"));
+    assert!(!is_anagram("stop", "stoop"));
+    assert!(!is_anagram("", ""));
+    for (a, b) in [("ΑΒΓ", "γβα"), ("go", "Go"), ("tan", "ant"), ("a", "b")] {
+        assert_eq!(
+            is_anagram(a, b),
+            anagrams_for(a, &[b]).contains(b),
+            "{a} {b}"
+        );
+    }
+}
+
+#[test]
+fn anagrams_for_opts_can_include_the_word_itself() {
+    let candidates = ["stop", "STOP", "pots", "stop", "spot", "post!"];
+    assert_eq!(
+        anagrams_for_opts("Stop", &candidates, true),
+        set(&["stop", "STOP", "pots", "spot"])
+    );
+    assert_eq!(
+        anagrams_for_opts("Stop", &candidates, false),
+        anagrams_for("Stop", &candidates)
+    );
+}
+
+#[test]
+fn anagrams_from_reader_streams_lines() {
+    let dictionary = "stop\npots\r\n\n   \nstop\nTOPS\nspots\nopts";
+    let anagrams = anagrams_from_reader("stop", dictionary.as_bytes()).unwrap();
+    assert_eq!(anagrams, vec!["pots", "TOPS", "opts"]);
+    assert!(
+        anagrams_from_reader("stop", "".as_bytes())
+            .unwrap()
+            .is_empty()
+    );
+}
+
+#[test]
+fn anagrams_from_reader_propagates_io_errors() {
+    struct Failing;
+    impl std::io::Read for Failing {
+        fn read(&mut self, _: &mut [u8]) -> std::io::Result<usize> {
+            Err(std::io::Error::other("disk on fire"))
+        }
+    }
+    let error = anagrams_from_reader("stop", std::io::BufReader::new(Failing)).unwrap_err();
+    assert_eq!(error.to_string(), "disk on fire");
+}
+
+#[test]
+fn anagrams_for_generic_accepts_owned_and_borrowed_strings() {
+    let owned = vec![
+        "enlists".to_string(),
+        "inlets".to_string(),
+        "Silent".to_string(),
+    ];
+    let matches = anagrams_for_generic("listen", &owned);
+    assert_eq!(matches, vec![&owned[1], &owned[2]]);
+    assert!(std::ptr::eq(matches[0], &owned[1]));
+
+    let cows: Vec<std::borrow::Cow<str>> = vec!["tinsel".into(), String::from("listen").into()];
+    assert_eq!(anagrams_for_generic("listen", &cows), vec![&cows[0]]);
+
+    let borrowed = ["tinsel", "tinsel"];
+    assert_eq!(
+        anagrams_for_generic("listen", &borrowed),
+        vec![&"tinsel", &"tinsel"]
+    );
+}
+
+#[test]
+fn count_anagrams_counts_distinct_matches() {
+    let candidates = ["enlists", "google", "inlets", "banana", "tinsel"];
+    assert_eq!(count_anagrams("listen", &candidates), 2);
+    assert_eq!(
+        count_anagrams("listen", &candidates),
+        anagrams_for("listen", &candidates).len()
+    );
+    // repeats count once, like the HashSet from anagrams_for
+    assert_eq!(count_anagrams("listen", &["inlets", "inlets"]), 1);
+    assert_eq!(count_anagrams("stop", &["pots", "pots"]), 1);
+    // different spellings are different entries in the set too
+    assert_eq!(count_anagrams("stop", &["pots", "POTS", "pots"]), 2);
+    assert_eq!(count_anagrams("listen", &[]), 0);
+}
+
+#[cfg(feature = "impl-ascii")]
+#[test]
+fn ascii_anagram_key_counts_letters() {
+    let mut expected = [0u8; 26];
+    expected[0] = 2;
+    expected[1] = 1;
+    assert_eq!(ascii_anagram_key("aBa"), Some(expected));
+    assert_eq!(ascii_anagram_key(""), Some([0; 26]));
+    assert_eq!(ascii_anagram_key("stop"), ascii_anagram_key("POTS"));
+    assert_eq!(ascii_anagram_key("naïve"), None);
+    assert_eq!(ascii_anagram_key("a-b"), None);
+    assert_eq!(ascii_anagram_key(&"a".repeat(256)), None);
+}
+
+#[cfg(feature = "impl-ascii")]
+#[test]
+fn anagrams_for_ascii_falls_back_for_non_ascii_words() {
+    let candidates = ["γβα", "ΒΓΑ", "αβγ"];
+    assert_eq!(
+        anagrams_for_ascii("ΑΒΓ", &candidates),
+        anagrams_for("ΑΒΓ", &candidates)
+    );
+    assert_eq!(
+        anagrams_for_ascii("ant", &["tan", "tän", "nta"]),
+        set(&["tan", "nta"])
+    );
+}
+
+#[test]
+fn anagrams_for_auto_agrees_on_ascii_and_unicode_words() {
+    let candidates = ["Stop", "pots", "ΑΒΓ", "γβα", "tops!", "opts"];
+    for word in ["stop", "STOP", "αβγ", "tops!", ""] {
+        assert_eq!(
+            anagrams_for_auto(word, &candidates),
+            anagrams_for(word, &candidates),
+            "{word}"
+        );
+    }
+}
+
+#[test]
+fn bench_compare_times_every_finder() {
+    let timings = bench_compare("stop", &["pots", "tops", "stop", "spot"], 5);
+    let names: Vec<&str> = FINDERS.iter().map(|finder| finder.name()).collect();
+    let mut sorted_names = names.clone();
+    sorted_names.sort_unstable();
+    assert_eq!(timings.keys().copied().collect::<Vec<_>>(), sorted_names);
+}
+
+#[test]
+fn finders_reflect_enabled_features() {
+    let names: Vec<&str> = FINDERS.iter().map(|finder| finder.name()).collect();
+    assert_eq!(names.contains(&"v1"), cfg!(feature = "impl-v1"));
+    assert_eq!(names.contains(&"ascii"), cfg!(feature = "impl-ascii"));
+    for always in ["v2", "v3", "v4", "auto"] {
+        assert!(names.contains(&always), "{always}");
+    }
+}
+
+#[test]
+#[should_panic(expected = "at least one iteration")]
+fn bench_compare_rejects_zero_iterations() {
+    bench_compare("stop", &[], 0);
+}
+
+// words where the Unicode and case handling of the implementations could split
+const AGREEMENT_FIXTURES: &[(&str, &[&str])] = &[
+    (
+        "listen",
+        &["enlists", "google", "inlets", "banana", "Silent", "LISTEN"],
+    ),
+    ("ΑΒΓ", &["ΒΓΑ", "ΒΓΔ", "γβα", "αβγ"]),
+    ("straße", &["STRASSE", "esartß", "Straße"]),
+    ("İstanbul", &["istanbul", "lubnatsİ", "ISTANBUL"]),
+    ("ı", &["I", "i", "ı"]),
+    ("", &["", " "]),
+    ("go", &["Go", "og", "GO"]),
+    ("éa", &["aé", "ae\u{301}", "AÉ"]),
+    // the Kelvin sign lowercases to a one-byte 'k' but is three bytes itself
+    ("ka", &["a\u{212A}", "ak", "AK", "\u{212A}a"]),
+];
+
+#[test]
+fn all_finders_agree_on_fixture_corpus() {
+    for (word, candidates) in AGREEMENT_FIXTURES {
+        assert_eq!(assert_agree(word, candidates), Ok(()), "{word}");
+        let index = AnagramIndex::build(candidates);
+        assert_eq!(
+            index.matches(word),
+            anagrams_for(word, candidates),
+            "{word}"
+        );
+    }
+}
+
+#[test]
+fn index_requires_equal_byte_length_like_anagrams_for() {
+    let index = AnagramIndex::build(&["a\u{212A}", "ak"]);
+    assert_eq!(index.matches("ka"), set(&["ak"]));
+    assert_eq!(anagrams_for("ka", &["a\u{212A}", "ak"]), set(&["ak"]));
+}
+
+#[test]
+fn divergence_names_finders_and_difference() {
+    let divergence = Divergence {
+        reference: "v1",
+        other: "v2",
+        symmetric_difference: ["tops".to_string()].into_iter().collect(),
+    };
+    assert_eq!(divergence.to_string(), "v1 and v2 disagree on {\"tops\"}");
+}
+
+// true when `found` is one of `candidates` itself, not just an equal string
+fn is_source_slice(found: &str, candidates: &[&str]) -> bool {
+    candidates
+        .iter()
+        .any(|candidate| std::ptr::eq(candidate.as_ptr(), found.as_ptr()) && *candidate == found)
+}
+
+#[test]
+fn results_keep_the_candidates_original_spelling() {
+    let candidates = ["PoTs", "sTOp", "tOpS", "OPTS"];
+    let expected = set(&["PoTs", "tOpS", "OPTS"]);
+    let word = "Stop";
+
+    for finder in FINDERS {
+        let found = finder.find(word, &candidates);
+        assert_eq!(found, expected, "{}", finder.name());
+        assert!(
+            found
+                .iter()
+                .all(|anagram| is_source_slice(anagram, &candidates)),
+            "{}",
+            finder.name()
+        );
+    }
+
+    let index = AnagramIndex::build(&candidates);
+    let borrowed: Vec<&str> = [
+        anagrams_for_sorted(word, &candidates),
+        anagrams_for_alphabetical(word, &candidates),
+        anagrams_for_indexed(word, &candidates)
+            .into_iter()
+            .map(|(_, anagram)| anagram)
+            .collect(),
+        anagrams_for_opts(word, &candidates, false)
+            .into_iter()
+            .collect(),
+        index.matches(word).into_iter().collect(),
+        LengthBuckets::build(&candidates)
+            .anagrams_for(word)
+            .into_iter()
+            .collect(),
+        group_anagrams(&candidates, 1).concat(),
+    ]
+    .concat();
+    assert!(
+        borrowed
+            .iter()
+            .all(|anagram| is_source_slice(anagram, &candidates))
+    );
+
+    let owned: Vec<String> = candidates
+        .iter()
+        .map(|candidate| candidate.to_string())
+        .collect();
+    let generic = anagrams_for_generic(word, &owned);
+    assert_eq!(generic, vec![&owned[0], &owned[2], &owned[3]]);
+
+    let reader = anagrams_from_reader(word, "PoTs\r\nsTOp\ntOpS\nOPTS\n".as_bytes()).unwrap();
+    assert_eq!(reader, vec!["PoTs", "tOpS", "OPTS"]);
+}
+
+#[test]
+fn anagram_index_round_trips_through_save_and_load() {
+    let candidates = ["Stop", "pots", "tops", "listen", "Silent", "ΑΒΓ", "γβα", ""];
+    let index = AnagramIndex::build(&candidates);
+    let mut saved = Vec::new();
+    index.save(&mut saved).unwrap();
+
+    let loaded = AnagramIndex::load(saved.as_slice()).unwrap();
+    for word in ["stop", "LISTEN", "αβγ", "", "nothing"] {
+        assert_eq!(loaded.matches(word), index.matches(word), "{word}");
+    }
+
+    // same dictionary, same bytes
+    let mut saved_again = Vec::new();
+    loaded.save(&mut saved_again).unwrap();
+    assert_eq!(saved_again, saved);
+}
+
+#[test]
+fn anagram_index_load_rejects_bad_input() {
+    use std::io::ErrorKind;
+
+    let error = AnagramIndex::load(&b"NOPE\0\0\0\0"[..]).err().unwrap();
+    assert_eq!(error.kind(), ErrorKind::InvalidData);
+
+    let mut saved = Vec::new();
+    AnagramIndex::build(&["stop", "pots"])
+        .save(&mut saved)
+        .unwrap();
+    let error = AnagramIndex::load(&saved[..saved.len() - 1]).err().unwrap();
+    assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+
+    // a string length pointing far past the end of the input
+    let mut corrupt = b"AIX1".to_vec();
+    corrupt.extend_from_slice(&1u32.to_le_bytes());
+    corrupt.extend_from_slice(&u32::MAX.to_le_bytes());
+    let error = AnagramIndex::load(corrupt.as_slice()).err().unwrap();
+    assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+}
+
+// an empty word only passes the length check for empty candidates, and those
+// equal the word, so every implementation returns nothing
+#[test]
+fn empty_word_has_no_anagrams_anywhere() {
+    let candidates = ["", "a", " ", ""];
+    for finder in FINDERS {
+        assert!(finder.find("", &candidates).is_empty(), "{}", finder.name());
+    }
+    assert!(anagrams_for("", &candidates).is_empty());
+    assert!(anagrams_for_sorted("", &candidates).is_empty());
+    assert!(anagrams_for_alphabetical("", &candidates).is_empty());
+    assert!(anagrams_for_indexed("", &candidates).is_empty());
+    assert!(anagrams_for_generic("", &candidates).is_empty());
+    assert!(anagrams_for_auto("", &candidates).is_empty());
+    assert!(anagrams_for_locale("", &candidates, Locale::Turkish).is_empty());
+    assert!(anagrams_for_phrases("", &candidates).is_empty());
+    assert!(AnagramIndex::build(&candidates).matches("").is_empty());
+    assert!(
+        LengthBuckets::build(&candidates)
+            .anagrams_for("")
+            .is_empty()
+    );
+    assert!(
+        anagrams_from_reader("", "\na\n\n".as_bytes())
+            .unwrap()
+            .is_empty()
+    );
+    assert_eq!(count_anagrams("", &candidates), 0);
+    assert!(!is_anagram("", ""));
+    assert_eq!(assert_agree("", &candidates), Ok(()));
+
+    // opting into self matches is the one way to get the empty candidate back
+    assert_eq!(anagrams_for_opts("", &candidates, true), set(&[""]));
+}
+
+#[cfg(feature = "unicode")]
+#[test]
+fn empty_word_has_no_unicode_anagrams() {
+    assert!(anagrams_for_unicode("", &["", "a"]).is_empty());
+    assert!(anagrams_for_ascii_folded("", &["", "a"]).is_empty());
+}
+
+#[cfg(feature = "graphemes")]
+#[test]
+fn empty_word_has_no_grapheme_anagrams() {
+    assert!(anagrams_for_graphemes("", &["", "a"]).is_empty());
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn empty_word_has_no_parallel_anagrams() {
+    assert!(anagrams_for_parallel("", &["", "a"]).is_empty());
+}
+
+#[test]
+fn anagrams_for_ranked_orders_longest_then_alphabetical() {
+    let candidates = ["tops", "Spot", "pots", "opts", "post", "tops"];
+    assert_eq!(
+        anagrams_for_ranked("stop", &candidates),
+        vec!["Spot", "opts", "post", "pots", "tops", "tops"]
+    );
+    // byte order puts uppercase before lowercase within a length
+    assert_eq!(anagrams_for_ranked("aé", &["éa", "Éa"]), vec!["Éa", "éa"]);
+    assert!(anagrams_for_ranked("stop", &[]).is_empty());
+}
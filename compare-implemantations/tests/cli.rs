@@ -0,0 +1,67 @@
+#![cfg(feature = "cli")]
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn run(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_playground"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn annotates_board_from_stdin() {
+    let output = run(&[], " * \n   \r\n");
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "1*1\n111\n");
+    assert_eq!(run(&["annotate"], "*\n").stdout, b"*\n");
+}
+
+#[test]
+fn reports_invalid_board() {
+    let output = run(&["annotate"], "**\n*\n");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(
+        String::from_utf8(output.stderr)
+            .unwrap()
+            .starts_with("error: row 1")
+    );
+}
+
+#[test]
+fn prints_anagrams_from_stdin() {
+    let output = run(&["anagram", "stop"], "pots\nstop\n\nTOPS\nspots\n");
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "pots\nTOPS\n");
+}
+
+#[test]
+fn empty_input_prints_nothing() {
+    for args in [&[][..], &["anagram", "stop"][..]] {
+        let output = run(args, "");
+        assert!(output.status.success());
+        assert!(output.stdout.is_empty());
+    }
+}
+
+#[test]
+fn unknown_command_prints_usage() {
+    let output = run(&["frobnicate"], "");
+    assert_eq!(output.status.code(), Some(2));
+    assert!(
+        String::from_utf8(output.stderr)
+            .unwrap()
+            .starts_with("usage:")
+    );
+}
@@ -0,0 +1,50 @@
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use minesweeper::{annotate, annotate_flat, generate};
+use std::hint::black_box;
+
+/// Square boards at ~15% mine density, from trivially small to millions of cells.
+const SIDES: [usize; 5] = [16, 64, 250, 1000, 2000];
+
+fn bench_serial_vs_parallel(c: &mut Criterion) {
+    let mut group = c.benchmark_group("annotate");
+    group.sample_size(20);
+
+    for side in SIDES {
+        let field = generate(side, side, side * side * 15 / 100, side as u64);
+        let rows: Vec<&str> = field.iter().map(String::as_str).collect();
+
+        group.bench_with_input(BenchmarkId::new("serial", side), &rows, |b, rows| {
+            b.iter(|| annotate(black_box(rows)))
+        });
+
+        #[cfg(feature = "rayon")]
+        group.bench_with_input(BenchmarkId::new("parallel", side), &rows, |b, rows| {
+            b.iter(|| minesweeper::annotate_parallel(black_box(rows)))
+        });
+    }
+
+    group.finish();
+}
+
+/// Nested `Vec<Vec<u8>>` working grid against the flat row-major buffer.
+fn bench_layouts(c: &mut Criterion) {
+    let mut group = c.benchmark_group("layout");
+    group.sample_size(20);
+
+    for side in SIDES {
+        let field = generate(side, side, side * side * 15 / 100, side as u64);
+        let rows: Vec<&str> = field.iter().map(String::as_str).collect();
+
+        group.bench_with_input(BenchmarkId::new("nested", side), &rows, |b, rows| {
+            b.iter(|| annotate(black_box(rows)))
+        });
+        group.bench_with_input(BenchmarkId::new("flat", side), &rows, |b, rows| {
+            b.iter(|| annotate_flat(black_box(rows)))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_serial_vs_parallel, bench_layouts);
+criterion_main!(benches);
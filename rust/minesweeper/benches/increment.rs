@@ -0,0 +1,94 @@
+//! Branchless `increment_cell_value` against a plain `match`.
+//!
+//! The crate's increment is private, so its body is copied here verbatim
+//! (without the debug assertion) and both versions run through the same
+//! driver: the [`NEIGHBOR_OFFSETS`] walk that [`annotate`] unrolls.
+//! `annotate` itself is included as a reference point.
+//!
+//! Two runs on the single-core sandbox at ~15% density (medians):
+//!
+//! | side | branchless      | match           | annotate        |
+//! |------|-----------------|-----------------|-----------------|
+//! | 250  | 432 / 382 µs    | 311 / 307 µs    | 399 / 406 µs    |
+//! | 1000 | 5.21 / 5.67 ms  | 4.07 / 3.83 ms  | 5.43 / 5.99 ms  |
+//! | 2000 | 18.6 / 25.5 ms  | 18.4 / 19.2 ms  | 25.1 / 26.5 ms  |
+//!
+//! The plain `match` was never slower and was 20–30% faster at the two
+//! smaller sizes; at 2000 the first run was within noise. So the bit tricks
+//! are not buying speed on this machine, and the benchmark should be rerun
+//! on other hardware before deciding whether to replace them.
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use minesweeper::{NEIGHBOR_OFFSETS, annotate, generate};
+use std::hint::black_box;
+
+const SIDES: [usize; 3] = [250, 1000, 2000];
+
+/// Copy of the crate's branchless increment.
+#[inline(always)]
+fn increment_branchless(cell: &mut u8) {
+    let current_value = *cell;
+    let is_not_mine = (current_value != b'*') as u8;
+    let is_empty_space = (current_value == b' ') as u8;
+    let is_digit = (((current_value & 0xF0) == 0x30) as u8) & (((current_value & 0x0F) <= 8) as u8);
+    *cell += is_not_mine * ((b'1' - b' ') * is_empty_space + is_digit);
+}
+
+/// The same update written as the obvious `match`.
+#[inline(always)]
+fn increment_match(cell: &mut u8) {
+    *cell = match *cell {
+        b' ' => b'1',
+        digit @ b'0'..=b'8' => digit + 1,
+        other => other,
+    };
+}
+
+/// Copies the board and increments every mine's in-bounds neighbors.
+fn annotate_using(rows: &[&str], increment: impl Fn(&mut u8)) -> Vec<Vec<u8>> {
+    let mut grid: Vec<Vec<u8>> = rows.iter().map(|row| row.as_bytes().to_vec()).collect();
+    let (total_rows, total_cols) = (grid.len(), grid[0].len());
+    for (row, row_str) in rows.iter().enumerate() {
+        for (col, &cell) in row_str.as_bytes().iter().enumerate() {
+            if cell != b'*' {
+                continue;
+            }
+            for (row_offset, col_offset) in NEIGHBOR_OFFSETS {
+                let target_row = row.wrapping_add_signed(row_offset);
+                let target_col = col.wrapping_add_signed(col_offset);
+                if (target_row < total_rows) & (target_col < total_cols) {
+                    increment(&mut grid[target_row][target_col]);
+                }
+            }
+        }
+    }
+    grid
+}
+
+fn bench_increment(c: &mut Criterion) {
+    let mut group = c.benchmark_group("increment");
+    group.sample_size(20);
+
+    for side in SIDES {
+        let field = generate(side, side, side * side * 15 / 100, side as u64);
+        let rows: Vec<&str> = field.iter().map(String::as_str).collect();
+        assert_eq!(
+            annotate_using(&rows, increment_branchless),
+            annotate_using(&rows, increment_match)
+        );
+
+        group.bench_with_input(BenchmarkId::new("branchless", side), &rows, |b, rows| {
+            b.iter(|| annotate_using(black_box(rows), increment_branchless))
+        });
+        group.bench_with_input(BenchmarkId::new("match", side), &rows, |b, rows| {
+            b.iter(|| annotate_using(black_box(rows), increment_match))
+        });
+        group.bench_with_input(BenchmarkId::new("annotate", side), &rows, |b, rows| {
+            b.iter(|| annotate(black_box(rows)))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_increment);
+criterion_main!(benches);
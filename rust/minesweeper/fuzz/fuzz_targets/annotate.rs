@@ -0,0 +1,92 @@
+//! Fuzzes `annotate` against a reference that loops over every neighbour.
+//!
+//! The branchless neighbour update and `increment_cell_value` bit tricks are
+//! easy to get subtly wrong at the edges, so the reference below deliberately
+//! uses the most obvious signed-offset loop instead.
+//!
+//! Run it from `rust/minesweeper` (cargo-fuzz needs a nightly toolchain):
+//!
+//! ```text
+//! cargo install cargo-fuzz
+//! cargo +nightly fuzz run annotate
+//! ```
+//!
+//! Add `-- -max_total_time=60` to stop after a minute. Crashing inputs are
+//! written to `fuzz/artifacts/annotate/`; replay one with
+//! `cargo +nightly fuzz run annotate fuzz/artifacts/annotate/<file>`.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// Largest side length the fuzzer builds; big enough to cover every edge case
+// while keeping each run fast.
+const MAX_SIDE: usize = 32;
+
+fuzz_target!(|data: &[u8]| {
+    let Some((&[shape, rows, cols], bits)) = data.split_first_chunk::<3>() else {
+        return;
+    };
+    let rows = usize::from(rows) % (MAX_SIDE + 1);
+    let cols = usize::from(cols) % (MAX_SIDE + 1);
+
+    // The first byte picks the shape so degenerate boards are hit on purpose
+    // rather than only by chance.
+    let (rows, cols) = match shape % 5 {
+        1 => (1, cols),
+        2 => (rows, 1),
+        _ => (rows, cols),
+    };
+    let is_mine = |index: usize| match shape % 5 {
+        3 => true,
+        4 => false,
+        _ => bits
+            .get(index / 8)
+            .is_some_and(|byte| byte & (1 << (index % 8)) != 0),
+    };
+
+    let minefield: Vec<String> = (0..rows)
+        .map(|row| {
+            (0..cols)
+                .map(|col| if is_mine(row * cols + col) { '*' } else { ' ' })
+                .collect()
+        })
+        .collect();
+    let minefield: Vec<&str> = minefield.iter().map(String::as_str).collect();
+
+    assert_eq!(minesweeper::annotate(&minefield), reference(&minefield));
+});
+
+fn reference(minefield: &[&str]) -> Vec<String> {
+    let grid: Vec<&[u8]> = minefield.iter().map(|row| row.as_bytes()).collect();
+    let rows = grid.len() as isize;
+    (0..rows)
+        .map(|row| {
+            let cols = grid[row as usize].len() as isize;
+            (0..cols)
+                .map(|col| {
+                    if grid[row as usize][col as usize] == b'*' {
+                        return '*';
+                    }
+                    let mut count = 0;
+                    for dr in -1..=1 {
+                        for dc in -1..=1 {
+                            let (r, c) = (row + dr, col + dc);
+                            if (dr, dc) != (0, 0)
+                                && (0..rows).contains(&r)
+                                && (0..cols).contains(&c)
+                                && grid[r as usize][c as usize] == b'*'
+                            {
+                                count += 1;
+                            }
+                        }
+                    }
+                    match count {
+                        0 => ' ',
+                        n => char::from(b'0' + n),
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
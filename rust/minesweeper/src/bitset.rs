@@ -0,0 +1,83 @@
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{MINE_CHAR, SPACE_CHAR, expect_valid, validate};
+
+/// Packs a raw minefield into one bit per cell.
+///
+/// Cells are numbered row-major, so cell `(row, col)` is bit
+/// `i = row × cols + col`. Bit `i` lives in word `i / 64` at position
+/// `i % 64`, counting from the least significant bit, and is set for a mine.
+/// Unused high bits of the last word are zero.
+///
+/// # Arguments
+/// * `minefield` - A raw minefield of `' '` and `'*'`
+///
+/// # Returns
+/// * `(usize, usize, Vec<u64>)` - `(rows, cols, bits)` with
+///   `ceil(rows × cols / 64)` words
+///
+/// # Panics
+/// Panics if the minefield is ragged or contains bytes other than `' '` and `'*'`.
+pub fn to_bitset(minefield: &[&str]) -> (usize, usize, Vec<u64>) {
+    expect_valid(validate(minefield));
+    let rows = minefield.len();
+    let cols = minefield.first().map_or(0, |row_str| row_str.len());
+
+    let mut bits = vec![0u64; (rows * cols).div_ceil(64)];
+    for (row, row_str) in minefield.iter().enumerate() {
+        for (col, &cell) in row_str.as_bytes().iter().enumerate() {
+            let index = row * cols + col;
+            bits[index / 64] |= u64::from(cell == MINE_CHAR) << (index % 64);
+        }
+    }
+    (rows, cols, bits)
+}
+
+/// Rebuilds the raw minefield packed by [`to_bitset`].
+///
+/// # Arguments
+/// * `rows` - Number of rows
+/// * `cols` - Number of columns
+/// * `bits` - Mine bits in the layout described on [`to_bitset`]
+///
+/// # Returns
+/// * `Vec<String>` - `rows` strings of `cols` bytes, each `' '` or `'*'`
+///
+/// # Panics
+/// Panics if `rows × cols` overflows, if `bits` does not hold exactly
+/// `ceil(rows × cols / 64)` words, or if any bit past the last cell is set.
+pub fn from_bitset(rows: usize, cols: usize, bits: &[u64]) -> Vec<String> {
+    let total_cells = rows
+        .checked_mul(cols)
+        .unwrap_or_else(|| panic!("a {rows}x{cols} board has too many cells"));
+    assert_eq!(
+        bits.len(),
+        total_cells.div_ceil(64),
+        "wrong number of bitset words for a {rows}x{cols} board"
+    );
+    if let Some(&last) = bits.last() {
+        let used = total_cells % 64;
+        assert!(
+            used == 0 || last >> used == 0,
+            "bits past the last cell of a {rows}x{cols} board are set"
+        );
+    }
+
+    (0..rows)
+        .map(|row| {
+            let row_bytes = (0..cols)
+                .map(|col| {
+                    let index = row * cols + col;
+                    if bits[index / 64] >> (index % 64) & 1 == 1 {
+                        MINE_CHAR
+                    } else {
+                        SPACE_CHAR
+                    }
+                })
+                .collect();
+            String::from_utf8(row_bytes).unwrap()
+        })
+        .collect()
+}
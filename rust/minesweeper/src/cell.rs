@@ -0,0 +1,104 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{AnnotateError, DIGIT_ONE, MINE_CHAR, SPACE_CHAR};
+
+/// A board cell that the neighbor-counting algorithm can annotate.
+///
+/// Implement this for your own cell enum to reuse [`annotate_generic`]
+/// without converting to and from ASCII.
+pub trait Cell {
+    /// Returns `true` if this cell is a mine.
+    fn is_mine(&self) -> bool;
+
+    /// Builds the annotated cell for a non-mine with `count` adjacent mines (0–8).
+    fn with_count(count: u8) -> Self;
+}
+
+/// The ASCII representation used by [`crate::annotate`], as a [`Cell`].
+///
+/// `b'*'` is a mine; annotated cells are `b' '` for zero and `b'1'`–`b'8'`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ByteCell(pub u8);
+
+impl Cell for ByteCell {
+    fn is_mine(&self) -> bool {
+        self.0 == MINE_CHAR
+    }
+
+    fn with_count(count: u8) -> Self {
+        match count {
+            0 => ByteCell(SPACE_CHAR),
+            count => ByteCell(DIGIT_ONE + count - 1),
+        }
+    }
+}
+
+/// Annotates a grid of arbitrary [`Cell`]s.
+///
+/// Mine cells are cloned unchanged; every other cell is replaced by
+/// `T::with_count(n)` where `n` is its number of adjacent mines. This is the
+/// representation-independent form of [`crate::annotate`], which keeps its own
+/// byte-specialized fast path instead of going through [`ByteCell`].
+///
+/// # Arguments
+/// * `grid` - Rows of cells, all of the same length
+///
+/// # Returns
+/// * `Vec<Vec<T>>` - The annotated grid with the same dimensions
+///
+/// # Panics
+/// Panics if the rows differ in length.
+pub fn annotate_generic<T: Cell + Clone>(grid: &[Vec<T>]) -> Vec<Vec<T>> {
+    let total_rows = grid.len();
+    let total_cols = grid.first().map_or(0, Vec::len);
+    if let Some(row_index) = grid.iter().position(|row| row.len() != total_cols) {
+        panic!(
+            "invalid minefield: {}",
+            AnnotateError::RaggedRows {
+                row: row_index,
+                expected: total_cols,
+                found: grid[row_index].len(),
+            }
+        );
+    }
+
+    // Phase 1: Accumulate counts by scanning mines, as in the byte path
+    let mut counts = vec![vec![0u8; total_cols]; total_rows];
+    for (mine_row, row) in grid.iter().enumerate() {
+        for (mine_col, cell) in row.iter().enumerate() {
+            if !cell.is_mine() {
+                continue;
+            }
+            for row_offset in -1..=1isize {
+                for col_offset in -1..=1isize {
+                    let target_row = mine_row.wrapping_add_signed(row_offset);
+                    let target_col = mine_col.wrapping_add_signed(col_offset);
+                    if (row_offset, col_offset) != (0, 0)
+                        && target_row < total_rows
+                        && target_col < total_cols
+                    {
+                        counts[target_row][target_col] += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    // Phase 2: Build the output cells
+    grid.iter()
+        .zip(counts)
+        .map(|(row, row_counts)| {
+            row.iter()
+                .zip(row_counts)
+                .map(|(cell, count)| {
+                    if cell.is_mine() {
+                        cell.clone()
+                    } else {
+                        T::with_count(count)
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
@@ -0,0 +1,56 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::{
+    DIGIT_ONE, MINE_CHAR, NEIGHBOR_OFFSETS, SPACE_CHAR, expect_valid, grid_to_strings, parse_grid,
+};
+
+/// Annotates a minefield with plain signed coordinate math.
+///
+/// A readable reference for [`crate::annotate`], which relies on wrapping
+/// arithmetic and a branchless unsigned bounds trick. Here every neighbor
+/// coordinate is computed as an `isize`, compared against `0` and the board
+/// size in the conventional way, and incremented with an ordinary `match`.
+/// The output is identical; the default path remains the faster one.
+///
+/// # Arguments
+/// * `minefield` - A slice of string slices representing the minefield
+///
+/// # Returns
+/// * `Vec<String>` - The annotated minefield with mine counts
+///
+/// # Panics
+/// Panics if the minefield is ragged or contains bytes other than `' '` and `'*'`.
+pub fn annotate_checked(minefield: &[&str]) -> Vec<String> {
+    let mut grid = expect_valid(parse_grid(minefield, MINE_CHAR));
+    // Vec lengths never exceed isize::MAX, so these conversions cannot fail
+    let total_rows = isize::try_from(grid.len()).unwrap();
+    let total_cols = isize::try_from(grid.first().map_or(0, Vec::len)).unwrap();
+
+    for mine_row in 0..total_rows {
+        for mine_col in 0..total_cols {
+            if grid[mine_row as usize][mine_col as usize] != MINE_CHAR {
+                continue;
+            }
+            for (row_offset, col_offset) in NEIGHBOR_OFFSETS {
+                let target_row = mine_row + row_offset;
+                let target_col = mine_col + col_offset;
+                if target_row < 0
+                    || target_row >= total_rows
+                    || target_col < 0
+                    || target_col >= total_cols
+                {
+                    continue;
+                }
+                let cell = &mut grid[target_row as usize][target_col as usize];
+                *cell = match *cell {
+                    MINE_CHAR => MINE_CHAR,
+                    SPACE_CHAR => DIGIT_ONE,
+                    digit => digit + 1,
+                };
+            }
+        }
+    }
+
+    grid_to_strings(grid)
+}
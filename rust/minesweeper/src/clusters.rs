@@ -0,0 +1,60 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{MINE_CHAR, mine_positions, neighbors};
+
+/// Groups mines into connected clusters using 8-connectivity.
+///
+/// Two mines belong to the same cluster if they touch horizontally,
+/// vertically or diagonally, directly or through a chain of other mines.
+/// A board whose mines all end up in one large cluster in a corner is a sign
+/// of a poor generator; isolated mines show up as clusters of size 1.
+///
+/// # Arguments
+/// * `minefield` - A slice of string slices representing the minefield
+///
+/// # Returns
+/// * `Vec<Vec<(usize, usize)>>` - One entry per cluster, ordered by each
+///   cluster's first mine in row-major order; coordinates inside a cluster
+///   are sorted row-major as well
+pub fn mine_clusters(minefield: &[&str]) -> Vec<Vec<(usize, usize)>> {
+    let is_mine = |row: usize, col: usize| {
+        minefield
+            .get(row)
+            .and_then(|row_str| row_str.as_bytes().get(col))
+            == Some(&MINE_CHAR)
+    };
+
+    let total_rows = minefield.len();
+    let total_cols = minefield.first().map_or(0, |row_str| row_str.len());
+    let mut visited: Vec<Vec<bool>> = minefield
+        .iter()
+        .map(|row_str| vec![false; row_str.len()])
+        .collect();
+    let mut clusters = Vec::new();
+
+    for (row, col) in mine_positions(minefield) {
+        if visited[row][col] {
+            continue;
+        }
+        visited[row][col] = true;
+
+        // Depth-first flood over touching mines
+        let mut cluster = Vec::new();
+        let mut stack = vec![(row, col)];
+        while let Some((row, col)) = stack.pop() {
+            cluster.push((row, col));
+            for (target_row, target_col) in neighbors(row, col, total_rows, total_cols) {
+                if is_mine(target_row, target_col) && !visited[target_row][target_col] {
+                    visited[target_row][target_col] = true;
+                    stack.push((target_row, target_col));
+                }
+            }
+        }
+
+        cluster.sort_unstable();
+        clusters.push(cluster);
+    }
+
+    clusters
+}
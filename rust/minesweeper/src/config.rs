@@ -0,0 +1,179 @@
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::wide::render_wide;
+use crate::{
+    MINE_CHAR, NEIGHBOR_OFFSETS, SPACE_CHAR, annotate_with, expect_valid, parse_grid, scan_mines,
+};
+
+/// The standard 8-cell neighborhood; the same offsets as [`NEIGHBOR_OFFSETS`].
+pub const OFFSETS_8: [(isize, isize); 8] = NEIGHBOR_OFFSETS;
+
+/// The 4 orthogonal neighbors.
+pub const OFFSETS_4: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+/// Builder for an [`Annotator`].
+///
+/// ```
+/// use minesweeper::{AnnotatorConfig, OFFSETS_8};
+///
+/// let annotator = AnnotatorConfig::new()
+///     .mine(b'X')
+///     .wrap(true)
+///     .offsets(&OFFSETS_8)
+///     .build();
+/// assert_eq!(annotator.annotate(&["X  "]), vec!["X11"]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnnotatorConfig {
+    mine: u8,
+    wrap: bool,
+    offsets: Vec<(isize, isize)>,
+    wide: bool,
+}
+
+impl Default for AnnotatorConfig {
+    fn default() -> Self {
+        AnnotatorConfig {
+            mine: MINE_CHAR,
+            wrap: false,
+            offsets: OFFSETS_8.to_vec(),
+            wide: false,
+        }
+    }
+}
+
+impl AnnotatorConfig {
+    /// Starts from the defaults, which reproduce [`crate::annotate`] exactly.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the byte that marks a mine (default `b'*'`).
+    pub fn mine(mut self, mine: u8) -> Self {
+        self.mine = mine;
+        self
+    }
+
+    /// Makes both axes wrap around like [`crate::annotate_toroidal`] (default `false`).
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Sets the neighborhood offsets (default [`OFFSETS_8`]).
+    pub fn offsets(mut self, offsets: &[(isize, isize)]) -> Self {
+        self.offsets = offsets.to_vec();
+        self
+    }
+
+    /// Renders counts above 9 with the wide encoding of
+    /// [`crate::wide_count_byte`] instead of panicking (default `false`).
+    pub fn wide(mut self, wide: bool) -> Self {
+        self.wide = wide;
+        self
+    }
+
+    /// Finalizes the configuration.
+    ///
+    /// # Panics
+    /// Panics if the mine marker is `' '` or an ASCII digit, or with
+    /// `wide(true)` also an ASCII letter, since letters are wide counts.
+    pub fn build(self) -> Annotator {
+        let is_count_glyph = if self.wide {
+            self.mine.is_ascii_alphanumeric()
+        } else {
+            self.mine.is_ascii_digit()
+        };
+        assert!(
+            self.mine != SPACE_CHAR && !is_count_glyph,
+            "mine marker {:?} collides with empty cells or counts",
+            self.mine as char
+        );
+        Annotator { config: self }
+    }
+}
+
+/// A configured annotator produced by [`AnnotatorConfig::build`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Annotator {
+    config: AnnotatorConfig,
+}
+
+impl Annotator {
+    /// Annotates a minefield with this annotator's settings.
+    ///
+    /// With wrapping enabled, offsets that land on the mine itself or on a
+    /// cell already reached from the same mine are skipped, matching
+    /// [`crate::annotate_toroidal`] on small boards.
+    ///
+    /// # Arguments
+    /// * `minefield` - A slice of string slices representing the minefield
+    ///
+    /// # Returns
+    /// * `Vec<String>` - The annotated minefield
+    ///
+    /// # Panics
+    /// Panics if the minefield is ragged or contains bytes other than `' '`
+    /// and the mine marker, or if a count exceeds 9 without `wide(true)`.
+    pub fn annotate(&self, minefield: &[&str]) -> Vec<String> {
+        let AnnotatorConfig {
+            mine,
+            wrap,
+            ref offsets,
+            wide,
+        } = self.config;
+
+        // The default neighborhood without wrapping is exactly the fast path
+        if !wrap && offsets[..] == OFFSETS_8 {
+            return annotate_with(minefield, mine);
+        }
+
+        // parse_grid checks every row before `counts` is sized from the width
+        let grid = expect_valid(parse_grid(minefield, mine));
+        let total_rows = grid.len();
+        let total_cols = grid.first().map_or(0, Vec::len);
+        let mut counts = vec![vec![0u32; total_cols]; total_rows];
+        let mut reached = Vec::with_capacity(offsets.len());
+
+        scan_mines(minefield, mine, |mine_row, mine_col| {
+            reached.clear();
+            for &(row_offset, col_offset) in offsets {
+                let target = if wrap {
+                    (
+                        wrap_index(mine_row, row_offset, total_rows),
+                        wrap_index(mine_col, col_offset, total_cols),
+                    )
+                } else {
+                    (
+                        mine_row.wrapping_add_signed(row_offset),
+                        mine_col.wrapping_add_signed(col_offset),
+                    )
+                };
+                let in_bounds = (target.0 < total_rows) & (target.1 < total_cols);
+                if !in_bounds || target == (mine_row, mine_col) || reached.contains(&target) {
+                    continue;
+                }
+                if wrap {
+                    reached.push(target);
+                }
+                counts[target.0][target.1] += 1;
+            }
+        });
+
+        if !wide {
+            let max_count = counts.iter().flatten().copied().max().unwrap_or(0);
+            assert!(
+                max_count <= 9,
+                "count {max_count} does not fit in one digit; enable wide counts"
+            );
+        }
+        render_wide(&grid, &counts, mine)
+    }
+}
+
+/// Adds a signed offset to `index` modulo `size`.
+fn wrap_index(index: usize, offset: isize, size: usize) -> usize {
+    (index as isize + offset).rem_euclid(size as isize) as usize
+}
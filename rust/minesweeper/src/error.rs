@@ -0,0 +1,110 @@
+use core::fmt;
+
+use crate::{AnnotateError, DecodeError, DiffError, ParseError};
+
+/// One error type for every fallible operation in the crate.
+///
+/// Each function keeps its own precise error type ([`AnnotateError`],
+/// [`ParseError`], [`DecodeError`], [`DiffError`]) so existing matches stay
+/// exhaustive, and every one of them converts into `MineError` with `From`.
+/// Code that mixes several operations can therefore return
+/// `Result<_, MineError>` and use `?` throughout.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MineError {
+    /// A row's byte length differs from the first row's.
+    RaggedRows {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// A cell holds a byte the operation does not accept.
+    InvalidChar { row: usize, col: usize, byte: u8 },
+    /// A coordinate lies outside a `rows × cols` board.
+    OutOfBounds {
+        row: usize,
+        col: usize,
+        rows: usize,
+        cols: usize,
+    },
+    /// A packed board could not be decoded.
+    Decode(DecodeError),
+    /// Two boards compared with [`crate::diff`] differ in shape.
+    Diff(DiffError),
+}
+
+impl fmt::Display for MineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MineError::RaggedRows {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "row {row} has length {found}, expected {expected} to match the first row"
+            ),
+            MineError::InvalidChar { row, col, byte } => {
+                write!(f, "invalid byte {byte:#04x} at row {row}, column {col}")
+            }
+            MineError::OutOfBounds {
+                row,
+                col,
+                rows,
+                cols,
+            } => write!(f, "cell ({row}, {col}) is outside the {rows}x{cols} board"),
+            MineError::Decode(err) => write!(f, "invalid packed board: {err}"),
+            MineError::Diff(err) => write!(f, "cannot compare boards: {err}"),
+        }
+    }
+}
+
+impl core::error::Error for MineError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            MineError::Decode(err) => Some(err),
+            MineError::Diff(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<AnnotateError> for MineError {
+    fn from(err: AnnotateError) -> Self {
+        match err {
+            AnnotateError::RaggedRows {
+                row,
+                expected,
+                found,
+            } => MineError::RaggedRows {
+                row,
+                expected,
+                found,
+            },
+            AnnotateError::InvalidChar { row, col, byte } => {
+                MineError::InvalidChar { row, col, byte }
+            }
+        }
+    }
+}
+
+impl From<ParseError> for MineError {
+    fn from(err: ParseError) -> Self {
+        MineError::InvalidChar {
+            row: err.row,
+            col: err.col,
+            byte: err.byte,
+        }
+    }
+}
+
+impl From<DecodeError> for MineError {
+    fn from(err: DecodeError) -> Self {
+        MineError::Decode(err)
+    }
+}
+
+impl From<DiffError> for MineError {
+    fn from(err: DiffError) -> Self {
+        MineError::Diff(err)
+    }
+}
@@ -0,0 +1,198 @@
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::grid::cell_count;
+use crate::{MINE_CHAR, SPACE_CHAR, count_mines, expect_valid, validate};
+
+/// Small, fast, seedable PRNG (SplitMix64).
+///
+/// Not cryptographically secure; it exists so generated boards are
+/// reproducible from a `u64` seed without pulling in an external crate.
+#[derive(Debug, Clone)]
+pub(crate) struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    pub(crate) fn new(seed: u64) -> Self {
+        SplitMix64 { state: seed }
+    }
+
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a uniformly distributed value in `0..bound`.
+    ///
+    /// Uses rejection sampling so small bounds are not biased by the modulo.
+    pub(crate) fn below(&mut self, bound: usize) -> usize {
+        let bound = bound as u64;
+        let zone = u64::MAX - (u64::MAX % bound);
+        loop {
+            let value = self.next_u64();
+            if value < zone {
+                return (value % bound) as usize;
+            }
+        }
+    }
+}
+
+/// Generates a raw (unannotated) minefield with exactly `mines` mines.
+///
+/// Mines are placed uniformly at random with a partial Fisher–Yates shuffle
+/// over the cell indices, driven by a seeded PRNG so the same arguments always
+/// produce the same layout.
+///
+/// # Arguments
+/// * `rows` - Number of rows in the generated field
+/// * `cols` - Number of columns in the generated field
+/// * `mines` - Exact number of mines to place
+/// * `seed` - PRNG seed; equal seeds give equal fields
+///
+/// # Returns
+/// * `Vec<String>` - `rows` strings of `cols` bytes, each `' '` or `'*'`
+///
+/// # Panics
+/// Panics if `rows × cols` does not fit in `usize`, or if `mines` exceeds it.
+pub fn generate(rows: usize, cols: usize, mines: usize, seed: u64) -> Vec<String> {
+    let total_cells = cell_count(rows, cols);
+    assert!(
+        mines <= total_cells,
+        "cannot place {mines} mines in a {rows}x{cols} field"
+    );
+
+    let mut rng = SplitMix64::new(seed);
+    let mut cell_indices: Vec<usize> = (0..total_cells).collect();
+    let mut cells = vec![SPACE_CHAR; total_cells];
+
+    // Only the first `mines` positions of the shuffle are needed
+    for placed in 0..mines {
+        let pick = placed + rng.below(total_cells - placed);
+        cell_indices.swap(placed, pick);
+        cells[cell_indices[placed]] = MINE_CHAR;
+    }
+
+    (0..rows)
+        .map(|row| String::from_utf8(cells[row * cols..(row + 1) * cols].to_vec()).unwrap())
+        .collect()
+}
+
+/// Moves every mine of a raw minefield to new random positions.
+///
+/// The result has the same dimensions and the same number of mines as
+/// `field`, placed by [`generate`] with the same seeded PRNG, so a board's
+/// size and density can be reused with a different layout on each seed.
+/// The new layout depends only on the dimensions, mine count and `seed`, not
+/// on where the mines were.
+///
+/// # Arguments
+/// * `field` - A raw minefield of `' '` and `'*'`
+/// * `seed` - PRNG seed; equal seeds give equal layouts
+///
+/// # Returns
+/// * `Vec<String>` - The shuffled raw field
+///
+/// # Panics
+/// Panics if the minefield is ragged or contains bytes other than `' '` and `'*'`.
+pub fn shuffle_mines(field: &[&str], seed: u64) -> Vec<String> {
+    expect_valid(validate(field));
+    let rows = field.len();
+    let cols = field.first().map_or(0, |row_str| row_str.len());
+    generate(rows, cols, count_mines(field), seed)
+}
+
+/// Side length of the square tiles [`generate_balanced`] spreads mines over.
+pub const BALANCE_TILE: usize = 4;
+
+/// Generates a raw minefield close to `target_density`, spread evenly across the board.
+///
+/// The board is cut into [`BALANCE_TILE`]-sided tiles (smaller at the right
+/// and bottom edges) and every tile receives its share of the mines,
+/// `target_density × tile area`, before any mine is placed. Fractional shares
+/// are settled by rounding the running total over the tiles in a shuffled
+/// order, so the overall count is exactly `round(target_density × cells)` and
+/// the leftover mines land on random tiles. Within a tile, cells are chosen
+/// uniformly. Compared with [`generate`], this rules out the large mine-free
+/// regions that make a board open up in one click, while staying
+/// reproducible from `seed`.
+///
+/// # Arguments
+/// * `rows` - Number of rows in the generated field
+/// * `cols` - Number of columns in the generated field
+/// * `target_density` - Desired fraction of mined cells, from 0.0 to 1.0
+/// * `seed` - PRNG seed; equal arguments give equal fields
+///
+/// # Returns
+/// * `(Vec<String>, f64)` - The raw field and its achieved density
+///   (mines / cells, 0.0 for a board with no cells)
+///
+/// # Panics
+/// Panics if `target_density` is not within `0.0..=1.0`, or if `rows × cols`
+/// does not fit in `usize`.
+pub fn generate_balanced(
+    rows: usize,
+    cols: usize,
+    target_density: f64,
+    seed: u64,
+) -> (Vec<String>, f64) {
+    assert!(
+        (0.0..=1.0).contains(&target_density),
+        "target density {target_density} is not between 0 and 1"
+    );
+
+    let mut rng = SplitMix64::new(seed);
+    let mut cells = vec![SPACE_CHAR; cell_count(rows, cols)];
+
+    let mut tiles = Vec::new();
+    for top in (0..rows).step_by(BALANCE_TILE) {
+        for left in (0..cols).step_by(BALANCE_TILE) {
+            tiles.push((top, left));
+        }
+    }
+    for placed in 0..tiles.len() {
+        let pick = placed + rng.below(tiles.len() - placed);
+        tiles.swap(placed, pick);
+    }
+
+    let mut covered_area = 0;
+    let mut mines = 0;
+    for (top, left) in tiles {
+        let tile_cells: Vec<usize> = (top..(top + BALANCE_TILE).min(rows))
+            .flat_map(|row| {
+                (left..(left + BALANCE_TILE).min(cols)).map(move |col| row * cols + col)
+            })
+            .collect();
+
+        // Rounding the running total keeps the overall count exact
+        covered_area += tile_cells.len();
+        let quota = (round(covered_area as f64 * target_density) - mines).min(tile_cells.len());
+        mines += quota;
+
+        let mut tile_cells = tile_cells;
+        for placed in 0..quota {
+            let pick = placed + rng.below(tile_cells.len() - placed);
+            tile_cells.swap(placed, pick);
+            cells[tile_cells[placed]] = MINE_CHAR;
+        }
+    }
+
+    let field = (0..rows)
+        .map(|row| String::from_utf8(cells[row * cols..(row + 1) * cols].to_vec()).unwrap())
+        .collect();
+    let achieved = if cells.is_empty() {
+        0.0
+    } else {
+        mines as f64 / cells.len() as f64
+    };
+    (field, achieved)
+}
+
+/// Rounds a non-negative value half up; `f64::round` needs `std`.
+fn round(value: f64) -> usize {
+    (value + 0.5) as usize
+}
@@ -0,0 +1,247 @@
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{
+    AnnotateError, MINE_CHAR, MineError, SPACE_CHAR, check_rows, expect_valid, increment_cell_value,
+};
+
+/// A minefield parsed once into a flat, row-major byte buffer.
+///
+/// Cell `(row, col)` lives at `cells[row * cols + col]`, so the whole board
+/// is one allocation instead of one per row. Parse it once with
+/// [`Grid::parse`] and reuse it for repeated lookups or annotation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid {
+    rows: usize,
+    cols: usize,
+    cells: Vec<u8>,
+}
+
+impl Grid {
+    /// Validates a minefield and copies it into a flat grid.
+    ///
+    /// # Arguments
+    /// * `minefield` - A slice of string slices representing the minefield
+    ///
+    /// # Returns
+    /// * `Ok(Grid)` - The board's cells, row-major
+    /// * `Err(AnnotateError)` - If rows differ in length or contain an unexpected byte
+    ///
+    /// # Panics
+    /// Panics if `rows × cols` does not fit in `usize`.
+    pub fn parse(minefield: &[&str]) -> Result<Self, AnnotateError> {
+        let rows = minefield.len();
+        // Every row is checked before the buffer is sized from the width
+        let cols = check_rows(minefield, MINE_CHAR)?;
+
+        let mut cells = Vec::with_capacity(cell_count(rows, cols));
+        for row_str in minefield {
+            cells.extend_from_slice(row_str.as_bytes());
+        }
+
+        Ok(Grid { rows, cols, cells })
+    }
+
+    /// Creates a `rows × cols` grid of empty cells.
+    ///
+    /// # Panics
+    /// Panics if `rows × cols` does not fit in `usize`, rather than wrapping
+    /// to a smaller buffer.
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Grid {
+            rows,
+            cols,
+            cells: vec![SPACE_CHAR; cell_count(rows, cols)],
+        }
+    }
+
+    /// Returns the number of rows.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns (0 for an empty grid).
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns the byte at `(row, col)`, or `None` if it is out of range.
+    pub fn get(&self, row: usize, col: usize) -> Option<u8> {
+        if row < self.rows && col < self.cols {
+            Some(self.cells[row * self.cols + col])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the byte at `(row, col)`, reporting out-of-range coordinates.
+    ///
+    /// # Returns
+    /// * `Ok(u8)` - The cell's byte
+    /// * `Err(MineError::OutOfBounds)` - If `row` or `col` is past the edge
+    pub fn try_get(&self, row: usize, col: usize) -> Result<u8, MineError> {
+        self.get(row, col).ok_or(MineError::OutOfBounds {
+            row,
+            col,
+            rows: self.rows,
+            cols: self.cols,
+        })
+    }
+
+    /// Returns a new grid with every empty cell replaced by its mine count.
+    ///
+    /// The output uses the same bytes as [`crate::annotate`]: `'*'` for
+    /// mines, `' '` for zero and `'1'`–`'8'` otherwise.
+    pub fn annotate(&self) -> Grid {
+        let mut annotated = self.clone();
+        annotate_cells(&mut annotated.cells, self.rows, self.cols, MINE_CHAR);
+        annotated
+    }
+
+    /// Converts the grid into one `String` per row, like [`crate::annotate`] returns.
+    pub fn to_strings(&self) -> Vec<String> {
+        (0..self.rows)
+            .map(|row| {
+                let row_bytes = &self.cells[row * self.cols..(row + 1) * self.cols];
+                // The grid only ever holds ASCII, so this cannot fail
+                String::from_utf8(row_bytes.to_vec()).unwrap()
+            })
+            .collect()
+    }
+}
+
+/// Annotates a minefield through a flat row-major buffer.
+///
+/// Produces byte-identical output to [`crate::annotate`], but the working
+/// grid is a single allocation indexed as `row * stride + col` rather than
+/// one `Vec` per row. Rows are validated first, then copied straight into
+/// that buffer, and the output strings are cut straight out of it.
+///
+/// In the `layout` benchmark (single-core sandbox) the two layouts were
+/// within noise of each other from 250 to 2000 cells per side: the nested
+/// path only dereferences a row pointer once per row, so there is little
+/// pointer chasing to save. It is kept for callers that already hold flat
+/// buffers and as the basis of [`Grid`].
+///
+/// # Arguments
+/// * `minefield` - A slice of string slices representing the minefield
+///
+/// # Returns
+/// * `Vec<String>` - The annotated minefield with mine counts
+///
+/// # Panics
+/// Panics if the minefield is ragged or contains bytes other than `' '` and `'*'`.
+pub fn annotate_flat(minefield: &[&str]) -> Vec<String> {
+    let rows = minefield.len();
+    // Every row is checked before the buffer is sized from the width
+    let cols = expect_valid(check_rows(minefield, MINE_CHAR));
+    let stride = cols + 2;
+
+    let mut padded = vec![SPACE_CHAR; padded_len(rows, cols)];
+    for (row_index, row_str) in minefield.iter().enumerate() {
+        let start = (row_index + 1) * stride + 1;
+        padded[start..start + cols].copy_from_slice(row_str.as_bytes());
+    }
+
+    let source_rows = minefield.iter().map(|row_str| row_str.as_bytes());
+    annotate_padded(&mut padded, source_rows, cols, MINE_CHAR);
+
+    (0..rows)
+        .map(|row| {
+            let start = (row + 1) * stride + 1;
+            // The buffer only ever holds ASCII, so this cannot fail
+            String::from_utf8(padded[start..start + cols].to_vec()).unwrap()
+        })
+        .collect()
+}
+
+/// Returns `rows × cols`, the length of a flat buffer for the board.
+///
+/// # Panics
+/// Panics if the product does not fit in `usize`. Every flat index is below
+/// this length, so once it is computed the index math cannot overflow.
+pub(crate) fn cell_count(rows: usize, cols: usize) -> usize {
+    rows.checked_mul(cols)
+        .unwrap_or_else(|| panic!("a {rows}x{cols} board has more cells than usize can index"))
+}
+
+/// Returns `(rows + 2) × (cols + 2)`, the length of the bordered buffer.
+///
+/// # Panics
+/// Panics if the length does not fit in `usize`, like [`cell_count`].
+fn padded_len(rows: usize, cols: usize) -> usize {
+    rows.checked_add(2)
+        .zip(cols.checked_add(2))
+        .and_then(|(padded_rows, stride)| padded_rows.checked_mul(stride))
+        .unwrap_or_else(|| panic!("a {rows}x{cols} board has more cells than usize can index"))
+}
+
+/// Annotates a flat row-major buffer in place.
+///
+/// # Arguments
+/// * `cells` - `rows * cols` validated bytes, row-major
+/// * `rows` - Number of rows
+/// * `cols` - Number of columns
+/// * `mine` - The byte that marks a mine
+pub(crate) fn annotate_cells(cells: &mut [u8], rows: usize, cols: usize, mine: u8) {
+    if cols == 0 {
+        return;
+    }
+
+    let stride = cols + 2;
+    let mut padded = vec![SPACE_CHAR; padded_len(rows, cols)];
+    for (row, row_cells) in cells.chunks_exact(cols).enumerate() {
+        let start = (row + 1) * stride + 1;
+        padded[start..start + cols].copy_from_slice(row_cells);
+    }
+
+    annotate_padded(&mut padded, cells.chunks_exact(cols), cols, mine);
+
+    for (row, row_cells) in cells.chunks_exact_mut(cols).enumerate() {
+        let start = (row + 1) * stride + 1;
+        row_cells.copy_from_slice(&padded[start..start + cols]);
+    }
+}
+
+/// Annotates a buffer holding the board inside a one-cell border of spaces.
+///
+/// With the border every real cell has all 8 neighbors in bounds, reached by
+/// fixed index offsets, so no per-neighbor bounds check is needed. The border
+/// collects counts that callers simply drop. Mines are looked up in the
+/// untouched `source_rows` rather than in `padded`, so the scan never reads a
+/// byte the loop has just written.
+///
+/// # Arguments
+/// * `padded` - `(rows + 2) * (cols + 2)` bytes, row-major, with the border
+/// * `source_rows` - The original rows, `cols` bytes each
+/// * `cols` - Number of columns inside the border
+/// * `mine` - The byte that marks a mine
+fn annotate_padded<'a>(
+    padded: &mut [u8],
+    source_rows: impl Iterator<Item = &'a [u8]>,
+    cols: usize,
+    mine: u8,
+) {
+    let stride = cols + 2;
+    for (row, row_bytes) in source_rows.enumerate() {
+        for (col, &cell) in row_bytes.iter().enumerate() {
+            if cell != mine {
+                continue;
+            }
+            let center = (row + 1) * stride + col + 1;
+            for target in [
+                center - stride - 1,
+                center - stride,
+                center - stride + 1,
+                center - 1,
+                center + 1,
+                center + stride - 1,
+                center + stride,
+                center + stride + 1,
+            ] {
+                increment_cell_value(&mut padded[target], mine);
+            }
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::wide::render_wide;
+use crate::{MINE_CHAR, expect_valid, parse_grid};
+
+/// Annotates a stack of 2D minefields as one 3D board.
+///
+/// Every cell has up to 26 neighbors: the 8 around it in its own layer plus
+/// the 9 directly above and below in the adjacent layers. Because counts can
+/// reach 26, cells are rendered with the wide encoding described on
+/// [`crate::wide_count_byte`] (`'1'`–`'9'`, then `'A'` = 10 … `'Q'` = 26), so
+/// each cell is still exactly one byte.
+///
+/// # Arguments
+/// * `layers` - The layers from bottom to top; each a rectangular grid of
+///   identical dimensions
+///
+/// # Returns
+/// * `Vec<Vec<String>>` - The annotated layers in the same order
+///
+/// # Panics
+/// Panics if any layer is ragged, contains bytes other than `' '` and `'*'`,
+/// or has different dimensions from the first layer.
+pub fn annotate_3d(layers: &[Vec<&str>]) -> Vec<Vec<String>> {
+    let grids: Vec<Vec<Vec<u8>>> = layers
+        .iter()
+        .map(|layer| expect_valid(parse_grid(layer, MINE_CHAR)))
+        .collect();
+
+    let total_layers = grids.len();
+    let total_rows = grids.first().map_or(0, Vec::len);
+    let total_cols = grids
+        .first()
+        .and_then(|grid| grid.first())
+        .map_or(0, Vec::len);
+
+    for (layer_index, grid) in grids.iter().enumerate() {
+        let layer_cols = grid.first().map_or(0, Vec::len);
+        assert!(
+            grid.len() == total_rows && layer_cols == total_cols,
+            "invalid minefield: layer {layer_index} is {}x{layer_cols}, expected {total_rows}x{total_cols}",
+            grid.len()
+        );
+    }
+
+    let mut counts = vec![vec![vec![0u32; total_cols]; total_rows]; total_layers];
+
+    // Mine-centric scan over all 26 offsets, with the same wrapping bounds check
+    for (mine_layer, grid) in grids.iter().enumerate() {
+        for (mine_row, row_bytes) in grid.iter().enumerate() {
+            for (mine_col, &cell) in row_bytes.iter().enumerate() {
+                if cell != MINE_CHAR {
+                    continue;
+                }
+                for layer_offset in -1..=1isize {
+                    for row_offset in -1..=1isize {
+                        for col_offset in -1..=1isize {
+                            if (layer_offset, row_offset, col_offset) == (0, 0, 0) {
+                                continue;
+                            }
+                            let target_layer = mine_layer.wrapping_add_signed(layer_offset);
+                            let target_row = mine_row.wrapping_add_signed(row_offset);
+                            let target_col = mine_col.wrapping_add_signed(col_offset);
+                            if (target_layer < total_layers)
+                                & (target_row < total_rows)
+                                & (target_col < total_cols)
+                            {
+                                counts[target_layer][target_row][target_col] += 1;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    grids
+        .iter()
+        .zip(&counts)
+        .map(|(grid, layer_counts)| render_wide(grid, layer_counts, MINE_CHAR))
+        .collect()
+}
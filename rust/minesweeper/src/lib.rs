@@ -1,11 +1,465 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+pub mod bitset;
+pub mod cell;
+pub mod checked;
+pub mod clusters;
+pub mod config;
+pub mod error;
+pub mod generator;
+pub mod grid;
+pub mod layers;
+pub mod packed;
+#[cfg(feature = "rayon")]
+pub mod parallel;
+pub mod parse;
+pub mod render;
+pub mod reveal;
+pub mod solver;
+pub mod stats;
+pub mod stream;
+pub mod transform;
+pub mod verify;
+pub mod view;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+pub mod wide;
+
+pub use bitset::{from_bitset, to_bitset};
+pub use cell::{ByteCell, Cell, annotate_generic};
+pub use checked::annotate_checked;
+pub use clusters::mine_clusters;
+pub use config::{Annotator, AnnotatorConfig, OFFSETS_4, OFFSETS_8};
+pub use error::MineError;
+pub use generator::{BALANCE_TILE, generate, generate_balanced, shuffle_mines};
+pub use grid::{Grid, annotate_flat};
+pub use layers::annotate_3d;
+pub use packed::{DecodeError, from_packed, to_packed};
+#[cfg(feature = "rayon")]
+pub use parallel::annotate_parallel;
+pub use parse::{ParseError, parse_annotated};
+pub use render::{legend, legend_ansi, render_ansi};
+pub use reveal::reveal;
+pub use solver::{Deductions, UNKNOWN_CHAR, deduce};
+pub use stats::{FieldStats, field_stats};
+pub use stream::StreamAnnotator;
+pub use transform::{annotate_colmajor, flip_horizontal, rotate_ccw, rotate_cw, transpose};
+pub use verify::{
+    DiffError, annotate_naive, diff, find_mismatch, is_annotated, is_annotated_strict, verify,
+};
+pub use view::{GridView, annotate_view, cell_at};
+#[cfg(feature = "wasm")]
+pub use wasm::annotate_js;
+pub use wide::{
+    MAX_WIDE_COUNT, annotate_radius, annotate_weighted, annotate_wide, wide_count_byte,
+};
+
 const SPACE_CHAR: u8 = b' '; // ASCII 32: Empty cell representation
 const MINE_CHAR: u8 = b'*'; // ASCII 42: Mine marker
 const DIGIT_ONE: u8 = b'1'; // ASCII 49: Starting digit for mine count
 
+/// The 8 cells adjacent to a mine, as `(row_offset, col_offset)` pairs.
+///
+/// This is the canonical neighborhood used by [`annotate`]: top row left to
+/// right, then left and right, then bottom row left to right. The unrolled
+/// update in the default path visits exactly these offsets in this order and
+/// is kept in sync with this constant (a test checks that
+/// [`annotate_with_offsets`] over it matches [`annotate`]).
+pub const NEIGHBOR_OFFSETS: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// The count map for [`annotate_mapped`] that matches [`annotate`].
+pub const DEFAULT_COUNT_MAP: [u8; 9] = *b" 12345678";
+
+/// Value [`counts`] reports for mine cells, outside the `0..=8` count range.
+pub const MINE_SENTINEL: u8 = u8::MAX;
+
+/// Errors reported by [`try_annotate`] for malformed minefields.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AnnotateError {
+    /// A row's byte length differs from the first row's.
+    ///
+    /// This includes an empty row alongside non-empty ones, e.g. `["", "*"]`.
+    RaggedRows {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// A cell holds a byte other than `' '` or the mine marker.
+    InvalidChar { row: usize, col: usize, byte: u8 },
+}
+
+impl fmt::Display for AnnotateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnnotateError::RaggedRows {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "row {row} has length {found}, expected {expected} to match the first row"
+            ),
+            AnnotateError::InvalidChar { row, col, byte } => write!(
+                f,
+                "invalid byte {byte:#04x} at row {row}, column {col}; expected ' ' or a mine"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for AnnotateError {}
+
+/// Error returned by [`validate`]; the same diagnostics as [`try_annotate`].
+pub type ValidationError = AnnotateError;
+
+/// Checks that a minefield is well-formed without annotating it.
+///
+/// All rows must have the same byte length as the first row and may only
+/// contain `' '` and `'*'`. The first problem found, scanning in row-major
+/// order, is reported.
+///
+/// # Arguments
+/// * `minefield` - A slice of string slices representing the minefield
+///
+/// # Returns
+/// * `Ok(())` - If [`annotate`] would accept the minefield
+/// * `Err(ValidationError)` - The offending row, plus column and byte for bad characters
+pub fn validate(minefield: &[&str]) -> Result<(), ValidationError> {
+    check_rows(minefield, MINE_CHAR).map(|_| ())
+}
+
 /// Annotates a minefield by replacing empty spaces with adjacent mine counts.
 ///
+/// This is the infallible wrapper around [`try_annotate`]; behavior for
+/// well-formed input is identical.
+///
+/// # Zero-width rows
+/// Rows of width 0 are a valid board with no cells: `annotate(&["", ""])`
+/// returns `["", ""]`, one empty string per input row. Width is still taken
+/// from the first row, so mixing empty and non-empty rows such as `["", "*"]`
+/// is ragged and panics like any other width mismatch.
+///
+/// # The `debug-safe` feature
+/// With the `debug-safe` cargo feature enabled, this dispatches to
+/// [`annotate_checked`], which uses plain signed coordinate math instead of
+/// wrapping arithmetic and the unsigned bounds trick. The switch is opt-in
+/// rather than tied to `debug_assertions`, so an ordinary `cargo test` keeps
+/// exercising the fast path that release builds ship.
+///
+/// # Arguments
+/// * `minefield` - A slice of string slices representing the minefield
+///
+/// # Returns
+/// * `Vec<String>` - The annotated minefield with mine counts
+///
+/// # Panics
+/// Panics if the minefield is ragged or contains bytes other than `' '` and `'*'`.
+pub fn annotate(minefield: &[&str]) -> Vec<String> {
+    #[cfg(feature = "debug-safe")]
+    {
+        annotate_checked(minefield)
+    }
+    #[cfg(not(feature = "debug-safe"))]
+    {
+        annotate_with(minefield, MINE_CHAR)
+    }
+}
+
+/// Annotates a minefield that uses `mine` instead of `'*'` as the mine marker.
+///
+/// Empty cells are still `' '` and counts are still rendered as `'1'`–`'8'`;
+/// mine cells keep the supplied marker in the output.
+///
+/// # Arguments
+/// * `minefield` - A slice of string slices representing the minefield
+/// * `mine` - The byte that marks a mine, e.g. `b'X'` or `b'#'`
+///
+/// # Returns
+/// * `Vec<String>` - The annotated minefield with mine counts
+///
+/// # Panics
+/// Panics if `mine` is `' '` or an ASCII digit, since it would collide with
+/// empty cells or counts, or if the minefield is ragged or contains bytes
+/// other than `' '` and `mine`.
+pub fn annotate_with(minefield: &[&str], mine: u8) -> Vec<String> {
+    assert!(
+        mine != SPACE_CHAR && !mine.is_ascii_digit(),
+        "mine marker {:?} collides with empty cells or counts",
+        mine as char
+    );
+    expect_valid(annotate_core(minefield, mine))
+}
+
+/// Annotates a minefield and returns its dimensions alongside the result.
+///
+/// Dimensions are measured once from the input (`cols` is the first row's
+/// byte length, 0 for an empty minefield), so callers serializing or passing
+/// the grid across FFI do not need to re-derive them from the strings.
+///
+/// # Arguments
+/// * `minefield` - A slice of string slices representing the minefield
+///
+/// # Returns
+/// * `(usize, usize, Vec<String>)` - `(rows, cols, annotated)`, where
+///   `annotated` is exactly what [`annotate`] returns
+///
+/// # Panics
+/// Panics if the minefield is ragged or contains bytes other than `' '` and `'*'`.
+pub fn annotate_sized(minefield: &[&str]) -> (usize, usize, Vec<String>) {
+    let total_rows = minefield.len();
+    let total_cols = minefield.first().map_or(0, |row_str| row_str.len());
+    (total_rows, total_cols, annotate(minefield))
+}
+
+/// Returns each cell's adjacent-mine count as a number instead of a digit.
+///
+/// Counting is shared with [`annotate`]; only the final conversion differs,
+/// mapping `' '` to `0`, `'1'`–`'8'` to `1`–`8` and mines to
+/// [`MINE_SENTINEL`]. Handy for numeric consumers such as heatmaps.
+///
+/// # Arguments
+/// * `minefield` - A slice of string slices representing the minefield
+///
+/// # Returns
+/// * `Vec<Vec<u8>>` - One count per cell, row-major
+///
+/// # Panics
+/// Panics if the minefield is ragged or contains bytes other than `' '` and `'*'`.
+pub fn counts(minefield: &[&str]) -> Vec<Vec<u8>> {
+    let mut grid = expect_valid(annotate_grid(minefield, MINE_CHAR));
+    for cell in grid.iter_mut().flatten() {
+        *cell = match *cell {
+            MINE_CHAR => MINE_SENTINEL,
+            SPACE_CHAR => 0,
+            digit => digit - DIGIT_ONE + 1,
+        };
+    }
+    grid
+}
+
+/// Annotates a minefield into a caller-owned buffer, reusing its allocations.
+///
+/// `out` is cleared and refilled with one row per input row, which is the
+/// same output as [`annotate`]. Each existing `String` is turned back into
+/// its byte buffer and used as that row's working storage, so calls on
+/// boards no larger than the previous one reuse every row's capacity rather
+/// than allocating. The outer vector is converted with in-place collects,
+/// which keep its allocation as well. The caller owns `out` and decides how
+/// long the buffers live; nothing is retained between calls.
+///
+/// # Arguments
+/// * `minefield` - A slice of string slices representing the minefield
+/// * `out` - Buffer that receives the annotated rows
+///
+/// # Panics
+/// Panics if the minefield is ragged or contains bytes other than `' '` and
+/// `'*'`. Validation runs first, so `out` is untouched when this panics.
+pub fn annotate_into(minefield: &[&str], out: &mut Vec<String>) {
+    expect_valid(validate(minefield));
+    let total_rows = minefield.len();
+    let total_cols = minefield.first().map_or(0, |row_str| row_str.len());
+
+    // String and Vec<u8> share a layout, so these collects run in place
+    let mut grid: Vec<Vec<u8>> = core::mem::take(out)
+        .into_iter()
+        .map(String::into_bytes)
+        .collect();
+    grid.truncate(total_rows);
+    grid.resize_with(total_rows, Vec::new);
+    for (row_bytes, row_str) in grid.iter_mut().zip(minefield) {
+        row_bytes.clear();
+        row_bytes.extend_from_slice(row_str.as_bytes());
+    }
+
+    scan_mines(minefield, MINE_CHAR, |row_index, col_index| {
+        update_adjacent_cells(
+            &mut grid, row_index, col_index, total_rows, total_cols, MINE_CHAR,
+        );
+    });
+
+    *out = grid_to_strings(grid);
+}
+
+/// Annotates a multi-line board held in a single string.
+///
+/// A thin wrapper over [`annotate`] that saves callers the split and join:
+/// the board is split into lines, annotated and joined back with `'\n'`.
+/// Every line may end in `'\n'` or `"\r\n"`, and exactly one terminator
+/// after the last row is optional, so it is not read as an extra empty row.
+/// The output ends with `'\n'` exactly when the input does, which makes
+/// `"\n\n"` (two empty rows) round-trip unchanged.
+///
+/// # Arguments
+/// * `board` - The minefield rows joined with `'\n'` or `"\r\n"`
+///
+/// # Returns
+/// * `String` - The annotated rows joined with `'\n'`
+///
+/// # Panics
+/// Panics if the board is ragged or contains bytes other than `' '` and `'*'`
+/// (a `'\r'` not followed by `'\n'` included).
+pub fn annotate_str(board: &str) -> String {
+    expect_valid(try_annotate_str(board))
+}
+
+/// Annotates a multi-line board held in a single string, validating it first.
+///
+/// Splits and joins exactly like [`annotate_str`], but reports a ragged or
+/// malformed board as an error instead of panicking.
+///
+/// # Arguments
+/// * `board` - The minefield rows joined with `'\n'` or `"\r\n"`
+///
+/// # Returns
+/// * `Ok(String)` - The annotated rows joined with `'\n'`
+/// * `Err(AnnotateError)` - If rows differ in length or contain an unexpected byte
+pub fn try_annotate_str(board: &str) -> Result<String, AnnotateError> {
+    // `lines` strips one "\n" or "\r\n" from every line, including the last
+    let rows: Vec<&str> = board.lines().collect();
+    let mut output = try_annotate(&rows)?.join("\n");
+    if board.ends_with('\n') {
+        output.push('\n');
+    }
+    Ok(output)
+}
+
+/// Annotates a minefield, rendering zero-count cells as `empty_out`.
+///
+/// Input parsing is unchanged: empty cells are still `' '` in the minefield.
+/// Only the output differs, where every cell left at zero after counting is
+/// written as `empty_out`, e.g. `b'.'` for UIs that show zeros as dots.
+///
+/// # Arguments
+/// * `minefield` - A slice of string slices representing the minefield
+/// * `empty_out` - The byte written for cells with no adjacent mines
+///
+/// # Returns
+/// * `Vec<String>` - The annotated minefield with mine counts
+///
+/// # Panics
+/// Panics if `empty_out` is `'*'`, an ASCII digit or not ASCII, since the
+/// output would be ambiguous or not valid UTF-8, or if the minefield is
+/// ragged or contains bytes other than `' '` and `'*'`.
+pub fn annotate_with_empty(minefield: &[&str], empty_out: u8) -> Vec<String> {
+    assert!(
+        empty_out.is_ascii() && empty_out != MINE_CHAR && !empty_out.is_ascii_digit(),
+        "empty marker {:?} collides with mines or counts",
+        empty_out as char
+    );
+    let mut grid = expect_valid(annotate_grid(minefield, MINE_CHAR));
+    for cell in grid.iter_mut().flatten() {
+        if *cell == SPACE_CHAR {
+            *cell = empty_out;
+        }
+    }
+    grid_to_strings(grid)
+}
+
+/// Annotates a minefield, writing each count `n` as `map[n]`.
+///
+/// `map[0]` is the byte for cells with no adjacent mines and `map[1]` to
+/// `map[8]` the bytes for one to eight. [`DEFAULT_COUNT_MAP`] reproduces
+/// [`annotate`]. Mines are still written as `'*'`.
+///
+/// # Arguments
+/// * `minefield` - A slice of string slices representing the minefield
+/// * `map` - The output byte for each count from 0 to 8
+///
+/// # Returns
+/// * `Vec<String>` - The annotated minefield with mapped counts
+///
+/// # Panics
+/// Panics if any byte of `map` is `'*'` or not ASCII, since the output
+/// would be ambiguous or not valid UTF-8, or if the minefield is ragged or
+/// contains bytes other than `' '` and `'*'`.
+pub fn annotate_mapped(minefield: &[&str], map: &[u8; 9]) -> Vec<String> {
+    if let Some(count) = map
+        .iter()
+        .position(|&byte| !byte.is_ascii() || byte == MINE_CHAR)
+    {
+        panic!(
+            "count byte {:?} for {count} collides with mines or is not ASCII",
+            map[count] as char
+        );
+    }
+    let mut grid = counts(minefield);
+    for cell in grid.iter_mut().flatten() {
+        *cell = match *cell {
+            MINE_SENTINEL => MINE_CHAR,
+            count => map[usize::from(count)],
+        };
+    }
+    grid_to_strings(grid)
+}
+
+/// Annotates the known part of a partially revealed minefield.
+///
+/// Cells marked [`UNKNOWN_CHAR`] (`'?'`) are copied to the output unchanged:
+/// they are never incremented and never count as mines. Every `'*'` still
+/// increments its known empty neighbors, so the result is what [`annotate`]
+/// would give on the revealed cells if the unknown ones held no mines.
+///
+/// # Arguments
+/// * `minefield` - A slice of string slices containing `' '`, `'*'` and `'?'`
+///
+/// # Returns
+/// * `Vec<String>` - The annotated minefield with `'?'` cells left in place
+///
+/// # Panics
+/// Panics if the minefield is ragged or contains bytes other than `' '`,
+/// `'*'` and `'?'`.
+pub fn annotate_partial(minefield: &[&str]) -> Vec<String> {
+    let total_rows = minefield.len();
+    let total_cols = minefield.first().map_or(0, |row_str| row_str.len());
+    let mut result_grid = Vec::with_capacity(total_rows);
+    for (row_index, row_str) in minefield.iter().enumerate() {
+        // Unknown cells are validated as if they were empty
+        let known: Vec<u8> = row_str
+            .bytes()
+            .map(|byte| {
+                if byte == UNKNOWN_CHAR {
+                    SPACE_CHAR
+                } else {
+                    byte
+                }
+            })
+            .collect();
+        expect_valid(check_row(row_index, &known, total_cols, MINE_CHAR));
+        result_grid.push(row_str.as_bytes().to_vec());
+    }
+
+    scan_mines(minefield, MINE_CHAR, |mine_row, mine_col| {
+        for (target_row, target_col) in neighbors(mine_row, mine_col, total_rows, total_cols) {
+            let cell = &mut result_grid[target_row][target_col];
+            if *cell != UNKNOWN_CHAR {
+                increment_cell_value(cell, MINE_CHAR);
+            }
+        }
+    });
+
+    grid_to_strings(result_grid)
+}
+
+/// Annotates a minefield, validating its shape and contents first.
+///
 /// This function implements an optimized minesweeper annotation algorithm that:
-/// 1. Creates a mutable copy of the input grid
+/// 1. Creates a mutable copy of the input grid, validating each row
 /// 2. Finds all mines and increments their neighbors
 /// 3. Converts the result back to strings
 ///
@@ -16,28 +470,605 @@ const DIGIT_ONE: u8 = b'1'; // ASCII 49: Starting digit for mine count
 /// * `minefield` - A slice of string slices representing the minefield
 ///
 /// # Returns
+/// * `Ok(Vec<String>)` - The annotated minefield with mine counts
+/// * `Err(AnnotateError)` - If rows differ in length or contain an unexpected byte
+pub fn try_annotate(minefield: &[&str]) -> Result<Vec<String>, AnnotateError> {
+    annotate_core(minefield, MINE_CHAR)
+}
+
+/// Annotates a minefield, yielding each annotated row lazily.
+///
+/// Counting needs the whole board, so the byte grid is still computed (and
+/// validated) up front; only the per-row `String`s are produced on demand.
+/// This lets rows be streamed into a writer without holding both the byte
+/// grid and a full `Vec<String>` at once.
+///
+/// # Arguments
+/// * `minefield` - A slice of string slices representing the minefield
+///
+/// # Returns
+/// * `impl Iterator<Item = String>` - The annotated rows, top to bottom
+///
+/// # Panics
+/// Panics immediately, before the first row is yielded, if the minefield is
+/// ragged or contains bytes other than `' '` and `'*'`.
+pub fn annotate_iter(minefield: &[&str]) -> impl Iterator<Item = String> + use<> {
+    expect_valid(annotate_grid(minefield, MINE_CHAR))
+        .into_iter()
+        .map(|row_bytes| String::from_utf8(row_bytes).unwrap())
+}
+
+/// Shared implementation of [`try_annotate`] and [`annotate_with`].
+///
+/// # Arguments
+/// * `minefield` - A slice of string slices representing the minefield
+/// * `mine` - The byte that marks a mine (never `' '` or a digit)
+fn annotate_core(minefield: &[&str], mine: u8) -> Result<Vec<String>, AnnotateError> {
+    // Phase 3: Convert byte vectors back to UTF-8 strings
+    annotate_grid(minefield, mine).map(grid_to_strings)
+}
+
+/// Runs phases 1 and 2 of the annotation, returning the working byte grid.
+///
+/// # Arguments
+/// * `minefield` - A slice of string slices representing the minefield
+/// * `mine` - The byte that marks a mine (never `' '` or a digit)
+fn annotate_grid(minefield: &[&str], mine: u8) -> Result<Vec<Vec<u8>>, AnnotateError> {
+    // Phase 1: Validate and copy the input into a mutable working grid
+    let mut result_grid = parse_grid(minefield, mine)?;
+    let total_rows = result_grid.len();
+    let total_cols = result_grid.first().map_or(0, Vec::len);
+
+    // Edge case: zero-width rows were validated as all empty, so there are
+    // no cells to update and the rows are returned as they are
+    if total_cols == 0 {
+        return Ok(result_grid);
+    }
+
+    // Phase 2: Process mines and update adjacent cells
+    scan_mines(minefield, mine, |row_index, col_index| {
+        update_adjacent_cells(
+            &mut result_grid,
+            row_index,
+            col_index,
+            total_rows,
+            total_cols,
+            mine,
+        );
+    });
+
+    Ok(result_grid)
+}
+
+/// Annotates a minefield using a caller-supplied neighborhood.
+///
+/// Each `(row_offset, col_offset)` pair names a cell, relative to a mine,
+/// that the mine contributes to. Passing the 4 orthogonal offsets gives a
+/// "plus" neighborhood; passing the 8 knight moves gives a knight variant.
+/// Offsets that leave the board are skipped and a `(0, 0)` offset is ignored
+/// because mine cells are never incremented.
+///
+/// # Arguments
+/// * `minefield` - A slice of string slices representing the minefield
+/// * `offsets` - Relative neighbor positions, at most 8 of them
+///
+/// # Returns
 /// * `Vec<String>` - The annotated minefield with mine counts
-pub fn annotate(minefield: &[&str]) -> Vec<String> {
+///
+/// # Panics
+/// Panics if more than 8 offsets are given, since larger counts cannot be
+/// rendered as a single digit, or if the minefield is ragged or contains
+/// bytes other than `' '` and `'*'`.
+pub fn annotate_with_offsets(minefield: &[&str], offsets: &[(isize, isize)]) -> Vec<String> {
+    assert!(
+        offsets.len() <= 8,
+        "at most 8 neighbor offsets are supported, got {}",
+        offsets.len()
+    );
+
+    let mut result_grid = expect_valid(parse_grid(minefield, MINE_CHAR));
+    let total_rows = result_grid.len();
+    let total_cols = result_grid.first().map_or(0, Vec::len);
+
+    scan_mines(minefield, MINE_CHAR, |mine_row, mine_col| {
+        apply_offsets(
+            &mut result_grid,
+            mine_row,
+            mine_col,
+            total_rows,
+            total_cols,
+            offsets,
+        );
+    });
+
+    grid_to_strings(result_grid)
+}
+
+/// Neighbor offsets of a cell in an even row of an odd-r hex layout.
+const HEX_EVEN_ROW_OFFSETS: [(isize, isize); 6] =
+    [(-1, -1), (-1, 0), (0, -1), (0, 1), (1, -1), (1, 0)];
+
+/// Neighbor offsets of a cell in an odd row of an odd-r hex layout.
+const HEX_ODD_ROW_OFFSETS: [(isize, isize); 6] =
+    [(-1, 0), (-1, 1), (0, -1), (0, 1), (1, 0), (1, 1)];
+
+/// Annotates a hexagonal minefield stored in "odd-r" offset coordinates.
+///
+/// Odd rows are drawn shifted half a cell to the right, so each cell has 6
+/// neighbors whose columns depend on the row's parity. For a cell `X` at
+/// column `c`:
+///
+/// ```text
+///      even row r                    odd row r
+///  (r-1,c-1)  (r-1,c)            (r-1,c)  (r-1,c+1)
+/// (r,c-1)   X   (r,c+1)        (r,c-1)   X   (r,c+1)
+///  (r+1,c-1)  (r+1,c)            (r+1,c)  (r+1,c+1)
+/// ```
+///
+/// i.e. an even row looks up and down at columns `c-1` and `c`, and an odd
+/// row at columns `c` and `c+1`. Counts never exceed 6, so the single-digit
+/// output of [`annotate`] is kept.
+///
+/// # Arguments
+/// * `minefield` - A slice of string slices, one per hex row
+///
+/// # Returns
+/// * `Vec<String>` - The annotated minefield with mine counts
+///
+/// # Panics
+/// Panics if the minefield is ragged or contains bytes other than `' '` and `'*'`.
+pub fn annotate_hex(minefield: &[&str]) -> Vec<String> {
+    let mut result_grid = expect_valid(parse_grid(minefield, MINE_CHAR));
+    let total_rows = result_grid.len();
+    let total_cols = result_grid.first().map_or(0, Vec::len);
+
+    scan_mines(minefield, MINE_CHAR, |mine_row, mine_col| {
+        // Hex adjacency is symmetric, so the mine's own parity picks the set
+        let offsets = if mine_row % 2 == 0 {
+            &HEX_EVEN_ROW_OFFSETS
+        } else {
+            &HEX_ODD_ROW_OFFSETS
+        };
+        apply_offsets(
+            &mut result_grid,
+            mine_row,
+            mine_col,
+            total_rows,
+            total_cols,
+            offsets,
+        );
+    });
+
+    grid_to_strings(result_grid)
+}
+
+/// Increments every in-bounds cell at `offsets` relative to a mine.
+///
+/// # Arguments
+/// * `grid` - Mutable reference to the 2D byte grid being processed
+/// * `mine_row` - Row index of the mine (0-based)
+/// * `mine_col` - Column index of the mine (0-based)
+/// * `max_rows` - Total number of rows (for bounds checking)
+/// * `max_cols` - Total number of columns (for bounds checking)
+/// * `offsets` - Relative positions of the cells to increment
+#[inline(always)]
+fn apply_offsets(
+    grid: &mut [Vec<u8>],
+    mine_row: usize,
+    mine_col: usize,
+    max_rows: usize,
+    max_cols: usize,
+    offsets: &[(isize, isize)],
+) {
+    for &(row_offset, col_offset) in offsets {
+        // Same wrapping bounds check as process_neighbor_cell!
+        let target_row = mine_row.wrapping_add_signed(row_offset);
+        let target_col = mine_col.wrapping_add_signed(col_offset);
+        if (target_row < max_rows) & (target_col < max_cols) {
+            increment_cell_value(&mut grid[target_row][target_col], MINE_CHAR);
+        }
+    }
+}
+
+/// Annotates a minefield whose edges wrap around on both axes.
+///
+/// Column 0 is adjacent to the last column and the top row is adjacent to the
+/// bottom row, so every cell has up to 8 neighbors. On boards narrower or
+/// shorter than 3 cells several offsets wrap onto the same cell; each distinct
+/// neighbor is counted once and a mine never counts itself.
+///
+/// # Arguments
+/// * `minefield` - A slice of string slices representing the minefield
+///
+/// # Returns
+/// * `Vec<String>` - The annotated minefield with wrapped mine counts
+///
+/// # Panics
+/// Panics if the minefield is ragged or contains bytes other than `' '` and `'*'`.
+pub fn annotate_toroidal(minefield: &[&str]) -> Vec<String> {
+    let mut result_grid = expect_valid(parse_grid(minefield, MINE_CHAR));
+    let total_rows = result_grid.len();
+    let total_cols = result_grid.first().map_or(0, Vec::len);
+
+    scan_mines(minefield, MINE_CHAR, |row_index, col_index| {
+        update_adjacent_cells_toroidal(
+            &mut result_grid,
+            row_index,
+            col_index,
+            total_rows,
+            total_cols,
+            MINE_CHAR,
+        );
+    });
+
+    grid_to_strings(result_grid)
+}
+
+/// Lists the coordinates of every mine.
+///
+/// Uses the same scan as phase 2 of [`annotate`], so no working grid is built.
+///
+/// # Arguments
+/// * `minefield` - A slice of string slices representing the minefield
+///
+/// # Returns
+/// * `Vec<(usize, usize)>` - `(row, col)` of each `'*'` in row-major order
+pub fn mine_positions(minefield: &[&str]) -> Vec<(usize, usize)> {
+    let mut positions = Vec::new();
+    scan_mines(minefield, MINE_CHAR, |row_index, col_index| {
+        positions.push((row_index, col_index));
+    });
+    positions
+}
+
+/// Counts the mines in a minefield.
+///
+/// Iterates the raw bytes of each row directly; nothing is allocated.
+///
+/// # Arguments
+/// * `minefield` - A slice of string slices representing the minefield
+///
+/// # Returns
+/// * `usize` - The total number of `'*'` cells (0 for an empty minefield)
+pub fn count_mines(minefield: &[&str]) -> usize {
+    minefield
+        .iter()
+        .map(|row_str| {
+            row_str
+                .as_bytes()
+                .iter()
+                .filter(|&&cell| cell == MINE_CHAR)
+                .count()
+        })
+        .sum()
+}
+
+/// Strips the mine counts from an annotated minefield.
+///
+/// Every ASCII digit `'1'`–`'8'` becomes `' '`; mines, spaces and any other
+/// bytes are copied unchanged. For any valid annotated field `x`,
+/// `annotate(&deannotate(x))` reproduces `x`.
+///
+/// # Arguments
+/// * `annotated` - A slice of string slices holding an annotated minefield
+///
+/// # Returns
+/// * `Vec<String>` - The raw minefield of spaces and mines
+pub fn deannotate(annotated: &[&str]) -> Vec<String> {
+    annotated
+        .iter()
+        .map(|row_str| {
+            let row_bytes = row_str
+                .bytes()
+                .map(|cell| match cell {
+                    b'1'..=b'8' => SPACE_CHAR,
+                    other => other,
+                })
+                .collect();
+            // Only ASCII digits are replaced, so UTF-8 validity is preserved
+            String::from_utf8(row_bytes).unwrap()
+        })
+        .collect()
+}
+
+/// Annotates a byte grid in place, without allocating a copy.
+///
+/// Mines are never modified by an increment, so a single scan over the
+/// caller's grid can both find mines and update their neighbors. Mine cells
+/// are left untouched; spaces become `'1'` and digits are incremented exactly
+/// as in [`annotate`]. Peak memory stays at the size of the caller's grid.
+///
+/// # Arguments
+/// * `grid` - Mutable rows of `' '` and `'*'` bytes, all of the same length
+///
+/// Cell contents are not validated. A neighbor of a mine holding any byte
+/// other than `' '`, `'*'` or `'0'`–`'8'` (for example `'9'`) panics in debug
+/// builds and is left unchanged in release builds.
+///
+/// # Panics
+/// Panics if the rows differ in length, or, in debug builds, on an
+/// unrecognized byte next to a mine as described above.
+pub fn annotate_in_place(grid: &mut [Vec<u8>]) {
+    let total_rows = grid.len();
+    let total_cols = grid.first().map_or(0, Vec::len);
+
+    if let Some(row_index) = grid.iter().position(|row| row.len() != total_cols) {
+        panic!(
+            "invalid minefield: {}",
+            AnnotateError::RaggedRows {
+                row: row_index,
+                expected: total_cols,
+                found: grid[row_index].len(),
+            }
+        );
+    }
+
+    // Index-based scan: the grid is mutated while it is being read
+    for row_index in 0..total_rows {
+        for col_index in 0..total_cols {
+            if grid[row_index][col_index] == MINE_CHAR {
+                update_adjacent_cells(
+                    grid, row_index, col_index, total_rows, total_cols, MINE_CHAR,
+                );
+            }
+        }
+    }
+}
+
+/// Counts the mines adjacent to a single cell without annotating the board.
+///
+/// Only the 8 neighbors of `(row, col)` are inspected; the cell itself is
+/// never counted, even if it is a mine.
+///
+/// # Arguments
+/// * `minefield` - A slice of string slices representing the minefield
+/// * `row` - Row index of the queried cell (0-based)
+/// * `col` - Column index of the queried cell (0-based)
+///
+/// # Returns
+/// * `u8` - The number of adjacent mines (0–8), or 0 if `(row, col)` is out of range
+pub fn count_adjacent_mines(minefield: &[&str], row: usize, col: usize) -> u8 {
+    let Some(row_len) = minefield.get(row).map(|row_str| row_str.len()) else {
+        return 0;
+    };
+    if col >= row_len {
+        return 0;
+    }
+
+    let mut count = 0;
+    for (target_row, target_col) in neighbors(row, col, minefield.len(), row_len) {
+        // `get` still guards the column in case a neighboring row is shorter
+        let is_mine = minefield[target_row].as_bytes().get(target_col) == Some(&MINE_CHAR);
+        count += is_mine as u8;
+    }
+    count
+}
+
+/// Yields the in-bounds 8-neighbors of `(row, col)` on a `rows × cols` board.
+///
+/// Neighbors come in [`NEIGHBOR_OFFSETS`] order and the cell itself is never
+/// yielded. Coordinates are computed with checked arithmetic, so neighbors
+/// left of column 0 or above row 0 are dropped rather than wrapped.
+///
+/// # Arguments
+/// * `row` - Row index of the cell (0-based)
+/// * `col` - Column index of the cell (0-based)
+/// * `rows` - Number of rows on the board
+/// * `cols` - Number of columns on the board
+///
+/// # Returns
+/// * `impl Iterator<Item = (usize, usize)>` - Up to 8 `(row, col)` pairs
+pub fn neighbors(
+    row: usize,
+    col: usize,
+    rows: usize,
+    cols: usize,
+) -> impl Iterator<Item = (usize, usize)> {
+    NEIGHBOR_OFFSETS
+        .into_iter()
+        .filter_map(move |(row_offset, col_offset)| {
+            let target_row = row.checked_add_signed(row_offset)?;
+            let target_col = col.checked_add_signed(col_offset)?;
+            (target_row < rows && target_col < cols).then_some((target_row, target_col))
+        })
+}
+
+/// Annotates a minefield cell-by-`char` rather than byte-by-byte.
+///
+/// Equivalent to [`annotate_chars_with`] using `' '` for empty cells and
+/// `'*'` for mines. For pure-ASCII input prefer [`annotate`], which is faster.
+///
+/// # Arguments
+/// * `minefield` - A slice of string slices whose `char`s are the cells
+///
+/// # Returns
+/// * `Vec<String>` - The annotated minefield with mine counts
+///
+/// # Panics
+/// Panics if rows differ in `char` count or contain other characters.
+pub fn annotate_chars(minefield: &[&str]) -> Vec<String> {
+    annotate_chars_with(minefield, ' ', '*')
+}
+
+/// Annotates a minefield drawn with arbitrary (possibly multibyte) characters.
+///
+/// Each `char` is one cell, so boards using box-drawing characters or emoji
+/// are measured and counted correctly. Counts are still emitted as ASCII
+/// `'1'`–`'8'`; zero-count cells keep the `empty` character and mines keep
+/// the `mine` character.
+///
+/// Performance: the board is first translated into the byte representation,
+/// annotated by the same branchless core as [`annotate`], and translated
+/// back. That costs two extra passes, UTF-8 decoding of every cell, and two
+/// extra allocations per row, so the byte path remains the default for
+/// pure-ASCII input.
+///
+/// # Arguments
+/// * `minefield` - A slice of string slices whose `char`s are the cells
+/// * `empty` - The character marking an empty cell
+/// * `mine` - The character marking a mine
+///
+/// # Returns
+/// * `Vec<String>` - The annotated minefield with mine counts
+///
+/// # Panics
+/// Panics if `empty` equals `mine`, if either is an ASCII digit, if rows
+/// differ in `char` count, or if a cell is neither `empty` nor `mine`.
+pub fn annotate_chars_with(minefield: &[&str], empty: char, mine: char) -> Vec<String> {
+    assert!(
+        empty != mine && !empty.is_ascii_digit() && !mine.is_ascii_digit(),
+        "empty {empty:?} and mine {mine:?} must be distinct non-digit characters"
+    );
+
+    let total_cols = minefield
+        .first()
+        .map_or(0, |row_str| row_str.chars().count());
+
+    // Phase 1: Translate each char cell into the single-byte representation
+    let translated: Vec<String> = minefield
+        .iter()
+        .enumerate()
+        .map(|(row_index, row_str)| {
+            let row_bytes: String = row_str
+                .chars()
+                .enumerate()
+                .map(|(col_index, cell)| match cell {
+                    cell if cell == empty => SPACE_CHAR as char,
+                    cell if cell == mine => MINE_CHAR as char,
+                    cell => panic!(
+                        "invalid minefield: unexpected {cell:?} at row {row_index}, column {col_index}"
+                    ),
+                })
+                .collect();
+            if row_bytes.len() != total_cols {
+                panic!(
+                    "invalid minefield: {}",
+                    AnnotateError::RaggedRows {
+                        row: row_index,
+                        expected: total_cols,
+                        found: row_bytes.len(),
+                    }
+                );
+            }
+            row_bytes
+        })
+        .collect();
+    let translated_rows: Vec<&str> = translated.iter().map(String::as_str).collect();
+
+    // Phase 2: Reuse the byte path for the actual counting
+    let annotated = expect_valid(annotate_core(&translated_rows, MINE_CHAR));
+
+    // Phase 3: Map empty and mine bytes back to the caller's characters
+    annotated
+        .iter()
+        .map(|row_str| {
+            row_str
+                .bytes()
+                .map(|cell| match cell {
+                    SPACE_CHAR => empty,
+                    MINE_CHAR => mine,
+                    digit => digit as char,
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Validates a minefield and copies it into a mutable byte grid.
+///
+/// # Arguments
+/// * `minefield` - A slice of string slices representing the minefield
+/// * `mine` - The byte that marks a mine
+///
+/// # Returns
+/// * `Ok(Vec<Vec<u8>>)` - One byte vector per row, identical to the input
+/// * `Err(AnnotateError)` - If rows differ in length or contain an unexpected byte
+fn parse_grid(minefield: &[&str], mine: u8) -> Result<Vec<Vec<u8>>, AnnotateError> {
     // Handle edge case: empty minefield
     if minefield.is_empty() {
-        return vec![];
+        return Ok(vec![]);
     }
 
-    let total_rows = minefield.len();
     let total_cols = minefield[0].len();
 
     // Pre-allocate result vector with exact capacity to avoid reallocations
-    let mut result_grid = Vec::with_capacity(total_rows);
+    let mut result_grid = Vec::with_capacity(minefield.len());
 
-    // Phase 1: Initialize working grid by copying input data
     // Using extend_from_slice for efficient bulk copying of byte data
-    for row_str in minefield {
+    for (row_index, row_str) in minefield.iter().enumerate() {
+        let row_bytes_in = row_str.as_bytes();
+        check_row(row_index, row_bytes_in, total_cols, mine)?;
+
         let mut row_bytes = Vec::with_capacity(total_cols);
-        row_bytes.extend_from_slice(row_str.as_bytes());
+        row_bytes.extend_from_slice(row_bytes_in);
         result_grid.push(row_bytes);
     }
 
-    // Phase 2: Process mines and update adjacent cells
+    Ok(result_grid)
+}
+
+/// Checks every row's width and contents, returning the board's width.
+///
+/// Callers that size one buffer for the whole board run this first, so a
+/// wide first row followed by a ragged one is reported before anything is
+/// allocated from the first row's length.
+///
+/// # Arguments
+/// * `minefield` - A slice of string slices representing the minefield
+/// * `mine` - The byte that marks a mine
+///
+/// # Returns
+/// * `Ok(usize)` - The length shared by every row (0 for an empty board)
+/// * `Err(AnnotateError)` - The first ragged row or unexpected byte
+pub(crate) fn check_rows(minefield: &[&str], mine: u8) -> Result<usize, AnnotateError> {
+    let total_cols = minefield.first().map_or(0, |row_str| row_str.len());
+    for (row_index, row_str) in minefield.iter().enumerate() {
+        check_row(row_index, row_str.as_bytes(), total_cols, mine)?;
+    }
+    Ok(total_cols)
+}
+
+/// Checks one row's width and contents.
+///
+/// # Arguments
+/// * `row_index` - Index of the row, reported in errors
+/// * `row_bytes` - The row's raw bytes
+/// * `total_cols` - Width every row is expected to have
+/// * `mine` - The byte that marks a mine
+fn check_row(
+    row_index: usize,
+    row_bytes: &[u8],
+    total_cols: usize,
+    mine: u8,
+) -> Result<(), AnnotateError> {
+    if row_bytes.len() != total_cols {
+        return Err(AnnotateError::RaggedRows {
+            row: row_index,
+            expected: total_cols,
+            found: row_bytes.len(),
+        });
+    }
+    match row_bytes
+        .iter()
+        .position(|&byte| byte != SPACE_CHAR && byte != mine)
+    {
+        Some(col_index) => Err(AnnotateError::InvalidChar {
+            row: row_index,
+            col: col_index,
+            byte: row_bytes[col_index],
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Calls `on_mine(row, col)` for every mine in the minefield, in row-major order.
+///
+/// # Arguments
+/// * `minefield` - A slice of string slices representing the minefield
+/// * `mine` - The byte that marks a mine
+/// * `on_mine` - Callback invoked with the 0-based coordinates of each mine
+#[inline(always)]
+fn scan_mines(minefield: &[&str], mine: u8, mut on_mine: impl FnMut(usize, usize)) {
     // Use iterator with enumerate for idiomatic Rust (Clippy suggestion)
     for (row_index, current_row_str) in minefield.iter().enumerate() {
         let current_row_bytes = current_row_str.as_bytes();
@@ -45,30 +1076,34 @@ pub fn annotate(minefield: &[&str]) -> Vec<String> {
         // Process each column using iterator with enumerate (Clippy suggestion)
         for (col_index, &current_cell) in current_row_bytes.iter().enumerate() {
             // Use branchless comparison to avoid CPU branch prediction penalties
-            let is_mine_present = (current_cell == MINE_CHAR) as u8;
+            let is_mine_present = (current_cell == mine) as u8;
 
             // Only process neighbor updates if a mine is found
             // This is much more efficient than checking every cell's neighbors
             if is_mine_present != 0 {
-                update_adjacent_cells(
-                    &mut result_grid,
-                    row_index,
-                    col_index,
-                    total_rows,
-                    total_cols,
-                );
+                on_mine(row_index, col_index);
             }
         }
     }
+}
 
-    // Phase 3: Convert byte vectors back to UTF-8 strings
-    // Using unwrap() is safe here as we only work with valid ASCII characters
-    result_grid
-        .into_iter()
+/// Converts a working grid back into owned strings.
+///
+/// Using unwrap() is safe here as the grid only ever holds valid ASCII characters.
+fn grid_to_strings(grid: Vec<Vec<u8>>) -> Vec<String> {
+    grid.into_iter()
         .map(|row_bytes| String::from_utf8(row_bytes).unwrap())
         .collect()
 }
 
+/// Unwraps a validation result, panicking with the error's description.
+fn expect_valid<T>(result: Result<T, AnnotateError>) -> T {
+    match result {
+        Ok(value) => value,
+        Err(err) => panic!("invalid minefield: {err}"),
+    }
+}
+
 /// Updates all cells adjacent to a mine by incrementing their count or converting spaces to '1'.
 ///
 /// This function uses a macro for compile-time optimization and manual loop unrolling.
@@ -80,6 +1115,7 @@ pub fn annotate(minefield: &[&str]) -> Vec<String> {
 /// * `mine_col` - Column index of the mine (0-based)  
 /// * `max_rows` - Total number of rows (for bounds checking)
 /// * `max_cols` - Total number of columns (for bounds checking)
+/// * `mine` - The byte that marks a mine, left untouched when adjacent
 #[inline(always)]
 fn update_adjacent_cells(
     grid: &mut [Vec<u8>], // Changed from &mut Vec<Vec<u8>> to &mut [Vec<u8>] (Clippy suggestion)
@@ -87,6 +1123,7 @@ fn update_adjacent_cells(
     mine_col: usize,
     max_rows: usize,
     max_cols: usize,
+    mine: u8,
 ) {
     /// Macro to generate optimized code for each direction.
     /// Using macros instead of loops eliminates iterator overhead and enables
@@ -105,12 +1142,13 @@ fn update_adjacent_cells(
             // Bounds check using bitwise AND for branchless operation
             // This is faster than logical AND (&&) as it avoids short-circuit evaluation
             if (target_row < max_rows) & (target_col < max_cols) {
-                increment_cell_value(&mut grid[target_row][target_col]);
+                increment_cell_value(&mut grid[target_row][target_col], mine);
             }
         };
     }
 
     // Manual loop unrolling: Process all 8 adjacent cells
+    // (kept in sync with NEIGHBOR_OFFSETS, in the same order)
     // Direction layout:
     //   (-1,-1) (-1, 0) (-1, 1)
     //   ( 0,-1)  MINE   ( 0, 1)
@@ -126,24 +1164,84 @@ fn update_adjacent_cells(
     process_neighbor_cell!(1, 1); // Bottom-right diagonal
 }
 
+/// Updates all cells adjacent to a mine on a board whose edges wrap around.
+///
+/// Neighbor coordinates are computed modulo the board size, with an offset of
+/// -1 expressed as `size - 1` to stay in unsigned arithmetic. Offsets that wrap
+/// onto the mine itself or onto an already-visited neighbor are skipped so
+/// small boards are not double-counted.
+///
+/// # Arguments
+/// * `grid` - Mutable reference to the 2D byte grid being processed
+/// * `mine_row` - Row index of the mine (0-based)
+/// * `mine_col` - Column index of the mine (0-based)
+/// * `max_rows` - Total number of rows (the row modulus)
+/// * `max_cols` - Total number of columns (the column modulus)
+/// * `mine` - The byte that marks a mine, left untouched when adjacent
+fn update_adjacent_cells_toroidal(
+    grid: &mut [Vec<u8>],
+    mine_row: usize,
+    mine_col: usize,
+    max_rows: usize,
+    max_cols: usize,
+    mine: u8,
+) {
+    let mut visited = [(mine_row, mine_col); 9];
+    let mut visited_len = 1;
+
+    for row_offset in [max_rows - 1, 0, 1] {
+        for col_offset in [max_cols - 1, 0, 1] {
+            let target = (
+                (mine_row + row_offset) % max_rows,
+                (mine_col + col_offset) % max_cols,
+            );
+            if visited[..visited_len].contains(&target) {
+                continue;
+            }
+            visited[visited_len] = target;
+            visited_len += 1;
+            increment_cell_value(&mut grid[target.0][target.1], mine);
+        }
+    }
+}
+
 /// Increments a cell's mine count using branchless bit manipulation.
 ///
 /// This function uses advanced bit manipulation techniques to avoid conditional branches,
 /// which can cause CPU pipeline stalls due to branch misprediction.
 ///
 /// Logic:
-/// - If cell is a mine ('*'): no change
+/// - If cell is a mine (`mine`, normally '*'): no change
 /// - If cell is empty (' '): convert to '1'
 /// - If cell is a digit ('1'-'8'): increment by 1
 ///
+/// Any other byte, including `'9'` (whose low nibble fails the `<= 8` check),
+/// is not recognized and is left unchanged. Validated input never reaches
+/// that case because a cell has at most 8 neighbors, so debug builds assert
+/// it instead of silently producing a wrong count.
+///
 /// # Arguments
 /// * `cell` - Mutable reference to the cell byte to be updated
+/// * `mine` - The byte that marks a mine
+///
+/// # Panics
+/// In debug builds, panics if `cell` is not `mine`, `' '` or `'0'`–`'8'`.
+///
+/// The `increment` benchmark compares this against a plain `match`; see
+/// `benches/increment.rs` for the recorded numbers.
 #[inline(always)]
-fn increment_cell_value(cell: &mut u8) {
+fn increment_cell_value(cell: &mut u8, mine: u8) {
     let current_value = *cell;
+    debug_assert!(
+        current_value == mine
+            || current_value == SPACE_CHAR
+            || (b'0'..=b'8').contains(&current_value),
+        "cannot increment cell {:?}: expected ' ', the mine marker or '0'-'8'",
+        current_value as char
+    );
 
     // Generate boolean flags as u8 values (0 or 1) for branchless arithmetic
-    let is_not_mine = (current_value != MINE_CHAR) as u8; // 1 if not mine, 0 if mine
+    let is_not_mine = (current_value != mine) as u8; // 1 if not mine, 0 if mine
     let is_empty_space = (current_value == SPACE_CHAR) as u8; // 1 if space, 0 otherwise
 
     // Check if current value is a digit (ASCII '0'-'8')
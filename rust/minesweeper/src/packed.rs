@@ -0,0 +1,161 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::{MINE_CHAR, SPACE_CHAR};
+
+/// Nibble value used for a mine cell.
+const MINE_NIBBLE: u8 = 15;
+
+/// Size of the `rows`/`cols` header in bytes.
+const HEADER_LEN: usize = 8;
+
+/// Errors reported by [`from_packed`] for malformed input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The input is shorter than the 8-byte dimension header.
+    MissingHeader { found: usize },
+    /// The body length does not match the dimensions in the header.
+    LengthMismatch { expected: usize, found: usize },
+    /// The header's `rows × cols` does not fit in `usize` (32-bit targets).
+    TooLarge { rows: u32, cols: u32 },
+    /// A cell nibble is not 0–8 or 15 (or a padding nibble is not 0).
+    InvalidNibble { cell: usize, value: u8 },
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::MissingHeader { found } => write!(
+                f,
+                "packed board is {found} bytes, shorter than the {HEADER_LEN}-byte header"
+            ),
+            DecodeError::LengthMismatch { expected, found } => write!(
+                f,
+                "packed board is {found} bytes, expected {expected} for its dimensions"
+            ),
+            DecodeError::TooLarge { rows, cols } => write!(
+                f,
+                "packed board declares {rows}x{cols} cells, more than usize can index"
+            ),
+            DecodeError::InvalidNibble { cell, value } => {
+                write!(f, "invalid nibble {value} for cell {cell}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for DecodeError {}
+
+/// Packs an annotated minefield into a compact binary form.
+///
+/// Layout:
+/// - bytes 0–3: row count, `u32` little-endian
+/// - bytes 4–7: column count, `u32` little-endian
+/// - then one nibble per cell in row-major order, two cells per byte with the
+///   earlier cell in the low nibble. Values are the neighbor count `0`–`8`, or
+///   `15` for a mine. An odd cell count leaves the final high nibble as `0`.
+///
+/// # Arguments
+/// * `minefield` - An annotated minefield of `' '`, `'1'`–`'8'` and `'*'`
+///
+/// # Returns
+/// * `Vec<u8>` - The header followed by `ceil(rows × cols / 2)` packed bytes
+///
+/// # Panics
+/// Panics if the minefield is ragged, contains any other byte, or has more
+/// than `u32::MAX` rows or columns.
+pub fn to_packed(minefield: &[&str]) -> Vec<u8> {
+    let total_rows = minefield.len();
+    let total_cols = minefield.first().map_or(0, |row_str| row_str.len());
+    let header_rows = u32::try_from(total_rows).expect("too many rows to pack");
+    let header_cols = u32::try_from(total_cols).expect("too many columns to pack");
+
+    // Widths are checked before the buffer is sized from the first row
+    for (row_index, row_str) in minefield.iter().enumerate() {
+        assert!(
+            row_str.len() == total_cols,
+            "invalid minefield: row {row_index} has length {}, expected {total_cols}",
+            row_str.len()
+        );
+    }
+    let total_cells = total_rows
+        .checked_mul(total_cols)
+        .expect("too many cells to pack");
+
+    let mut packed = Vec::with_capacity(HEADER_LEN + total_cells.div_ceil(2));
+    packed.extend_from_slice(&header_rows.to_le_bytes());
+    packed.extend_from_slice(&header_cols.to_le_bytes());
+
+    let mut nibbles = minefield.iter().flat_map(|row_str| {
+        row_str.bytes().map(|cell| match cell {
+            SPACE_CHAR => 0,
+            b'1'..=b'8' => cell - b'0',
+            MINE_CHAR => MINE_NIBBLE,
+            other => panic!("invalid minefield: cannot pack byte {other:#04x}"),
+        })
+    });
+
+    while let Some(low) = nibbles.next() {
+        let high = nibbles.next().unwrap_or(0);
+        packed.push(low | (high << 4));
+    }
+    packed
+}
+
+/// Decodes a board produced by [`to_packed`].
+///
+/// # Arguments
+/// * `bytes` - The packed header and body
+///
+/// # Returns
+/// * `Ok(Vec<String>)` - The annotated minefield, identical to the packed input
+/// * `Err(DecodeError)` - If the header, length, or any nibble is invalid, or
+///   if the header's dimensions overflow `usize`
+pub fn from_packed(bytes: &[u8]) -> Result<Vec<String>, DecodeError> {
+    if bytes.len() < HEADER_LEN {
+        return Err(DecodeError::MissingHeader { found: bytes.len() });
+    }
+    let (header, body) = bytes.split_at(HEADER_LEN);
+    let header_rows = u32::from_le_bytes(header[0..4].try_into().unwrap());
+    let header_cols = u32::from_le_bytes(header[4..8].try_into().unwrap());
+    let (total_rows, total_cols) = (header_rows as usize, header_cols as usize);
+    // The dimensions are untrusted; on 32-bit targets their product can wrap
+    let total_cells = total_rows
+        .checked_mul(total_cols)
+        .ok_or(DecodeError::TooLarge {
+            rows: header_rows,
+            cols: header_cols,
+        })?;
+
+    let expected_len = total_cells.div_ceil(2);
+    if body.len() != expected_len {
+        return Err(DecodeError::LengthMismatch {
+            expected: HEADER_LEN + expected_len,
+            found: bytes.len(),
+        });
+    }
+    if total_cells % 2 == 1 && body[expected_len - 1] >> 4 != 0 {
+        return Err(DecodeError::InvalidNibble {
+            cell: total_cells,
+            value: body[expected_len - 1] >> 4,
+        });
+    }
+
+    let mut cells = Vec::with_capacity(total_cells);
+    for cell in 0..total_cells {
+        let nibble = (body[cell / 2] >> ((cell % 2) * 4)) & 0x0F;
+        cells.push(match nibble {
+            0 => SPACE_CHAR,
+            1..=8 => b'0' + nibble,
+            MINE_NIBBLE => MINE_CHAR,
+            value => return Err(DecodeError::InvalidNibble { cell, value }),
+        });
+    }
+
+    Ok((0..total_rows)
+        .map(|row| {
+            String::from_utf8(cells[row * total_cols..(row + 1) * total_cols].to_vec()).unwrap()
+        })
+        .collect())
+}
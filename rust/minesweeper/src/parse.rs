@@ -0,0 +1,58 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::{MINE_CHAR, SPACE_CHAR};
+
+/// Error returned by [`parse_annotated`] for a byte that is not a valid cell.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Row of the offending byte.
+    pub row: usize,
+    /// Byte offset of the offending byte within its row.
+    pub col: usize,
+    /// The offending byte.
+    pub byte: u8,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid byte {:#04x} at row {}, column {}; expected ' ', '1'-'8' or '*'",
+            self.byte, self.row, self.col
+        )
+    }
+}
+
+impl core::error::Error for ParseError {}
+
+/// Parses an annotated board back into numeric counts.
+///
+/// The numeric inverse of rendering: `' '` becomes `0`, `'1'`–`'8'` their
+/// value and `'*'` becomes `-1`. Rows are parsed independently, so ragged
+/// input is allowed and simply yields rows of different lengths.
+///
+/// # Arguments
+/// * `annotated` - An annotated minefield, as produced by [`crate::annotate`]
+///
+/// # Returns
+/// * `Ok(Vec<Vec<i8>>)` - One value per cell, row-major
+/// * `Err(ParseError)` - The first invalid byte in row-major order
+pub fn parse_annotated(annotated: &[&str]) -> Result<Vec<Vec<i8>>, ParseError> {
+    annotated
+        .iter()
+        .enumerate()
+        .map(|(row, row_str)| {
+            row_str
+                .bytes()
+                .enumerate()
+                .map(|(col, byte)| match byte {
+                    SPACE_CHAR => Ok(0),
+                    MINE_CHAR => Ok(-1),
+                    b'1'..=b'8' => Ok((byte - b'0') as i8),
+                    _ => Err(ParseError { row, col, byte }),
+                })
+                .collect()
+        })
+        .collect()
+}
@@ -0,0 +1,95 @@
+use alloc::string::String;
+
+use crate::{MINE_CHAR, SPACE_CHAR};
+
+/// Resets all attributes; emitted at the end of every line.
+const ANSI_RESET: &str = "\x1b[0m";
+
+/// Returns the escape sequence that styles one annotated cell.
+///
+/// Every sequence starts with `0;` so it fully replaces the previous cell's
+/// style. Digits follow the classic minesweeper palette, mines are bold red
+/// and empty cells get a dim grey background. Other bytes are unstyled.
+pub(crate) fn ansi_style(cell: u8) -> &'static str {
+    match cell {
+        b'1' => "\x1b[0;94m", // bright blue
+        b'2' => "\x1b[0;32m", // green
+        b'3' => "\x1b[0;91m", // bright red
+        b'4' => "\x1b[0;34m", // dark blue
+        b'5' => "\x1b[0;31m", // maroon
+        b'6' => "\x1b[0;36m", // cyan
+        b'7' => "\x1b[0;35m", // magenta (stands in for black)
+        b'8' => "\x1b[0;90m", // grey
+        MINE_CHAR => "\x1b[0;1;31m",
+        SPACE_CHAR => "\x1b[0;100m",
+        _ => ANSI_RESET,
+    }
+}
+
+/// Renders an annotated board for a terminal.
+///
+/// With `color` enabled each cell is preceded by its style from the classic
+/// palette (1 blue, 2 green, 3 red, 4 dark blue, 5 maroon, 6 cyan, 7 magenta,
+/// 8 grey, mines bold red, empty cells on a dim grey background) and every
+/// line ends with a reset so colors never leak into later output. With
+/// `color` disabled the board is returned as plain text. Either way each row
+/// is followed by `'\n'`.
+///
+/// # Arguments
+/// * `annotated` - An annotated minefield, as produced by [`crate::annotate`]
+/// * `color` - Whether to emit ANSI escape codes
+///
+/// # Returns
+/// * `String` - The rendered board
+pub fn render_ansi(annotated: &[&str], color: bool) -> String {
+    let mut rendered = String::new();
+    for row_str in annotated {
+        if color {
+            for cell in row_str.bytes() {
+                rendered.push_str(ansi_style(cell));
+                rendered.push(cell as char);
+            }
+            rendered.push_str(ANSI_RESET);
+        } else {
+            rendered.push_str(row_str);
+        }
+        rendered.push('\n');
+    }
+    rendered
+}
+
+/// Returns a plain-text key to the symbols of an annotated board.
+///
+/// One entry per line, each ending in `'\n'`, so it can be printed right
+/// below the output of [`render_ansi`].
+pub fn legend() -> String {
+    String::from("*     = mine\n1-8   = adjacent mines\nspace = no adjacent mines\n")
+}
+
+/// Returns the key from [`legend`] with each symbol styled as [`render_ansi`] draws it.
+///
+/// The digit entry shows all eight digits in their own colors, and the empty
+/// entry shows a styled blank cell in place of the word `space`. Every line
+/// ends with a reset, like a rendered board row.
+pub fn legend_ansi() -> String {
+    let mut rendered = String::new();
+
+    rendered.push_str(ansi_style(MINE_CHAR));
+    rendered.push(MINE_CHAR as char);
+    rendered.push_str(ANSI_RESET);
+    rendered.push_str("        = mine\n");
+
+    for digit in b'1'..=b'8' {
+        rendered.push_str(ansi_style(digit));
+        rendered.push(digit as char);
+    }
+    rendered.push_str(ANSI_RESET);
+    rendered.push_str(" = adjacent mines\n");
+
+    rendered.push_str(ansi_style(SPACE_CHAR));
+    rendered.push(SPACE_CHAR as char);
+    rendered.push_str(ANSI_RESET);
+    rendered.push_str("        = no adjacent mines\n");
+
+    rendered
+}
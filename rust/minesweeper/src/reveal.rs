@@ -0,0 +1,63 @@
+use alloc::collections::VecDeque;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{MINE_CHAR, SPACE_CHAR, neighbors};
+
+/// Computes the cells revealed by clicking `start` on an annotated board.
+///
+/// Follows the standard minesweeper rule: a zero cell (`' '`) reveals all of
+/// its neighbors and the flood continues from every zero cell reached, while
+/// a numbered cell is revealed but does not spread further. Mines are never
+/// revealed, so clicking a mine or an out-of-range coordinate reveals nothing.
+///
+/// # Arguments
+/// * `annotated` - An annotated minefield, as produced by [`crate::annotate`]
+/// * `start` - The clicked `(row, col)`
+///
+/// # Returns
+/// * `Vec<(usize, usize)>` - Every revealed coordinate, sorted in row-major order
+pub fn reveal(annotated: &[&str], start: (usize, usize)) -> Vec<(usize, usize)> {
+    let cell_at = |row: usize, col: usize| {
+        annotated
+            .get(row)
+            .and_then(|row_str| row_str.as_bytes().get(col))
+            .copied()
+    };
+
+    match cell_at(start.0, start.1) {
+        None | Some(MINE_CHAR) => return vec![],
+        Some(_) => {}
+    }
+
+    let total_rows = annotated.len();
+    let total_cols = annotated.first().map_or(0, |row_str| row_str.len());
+    let mut visited: Vec<Vec<bool>> = annotated
+        .iter()
+        .map(|row_str| vec![false; row_str.len()])
+        .collect();
+    let mut revealed = Vec::new();
+    let mut queue = VecDeque::from([start]);
+    visited[start.0][start.1] = true;
+
+    // Breadth-first flood from the clicked cell
+    while let Some((row, col)) = queue.pop_front() {
+        revealed.push((row, col));
+        if cell_at(row, col) != Some(SPACE_CHAR) {
+            continue;
+        }
+        for (target_row, target_col) in neighbors(row, col, total_rows, total_cols) {
+            match cell_at(target_row, target_col) {
+                None | Some(MINE_CHAR) => {}
+                Some(_) if !visited[target_row][target_col] => {
+                    visited[target_row][target_col] = true;
+                    queue.push_back((target_row, target_col));
+                }
+                Some(_) => {}
+            }
+        }
+    }
+
+    revealed.sort_unstable();
+    revealed
+}
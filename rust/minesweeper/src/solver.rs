@@ -0,0 +1,140 @@
+use alloc::collections::BTreeSet;
+use alloc::vec::Vec;
+
+use crate::{DIGIT_ONE, MINE_CHAR, SPACE_CHAR, neighbors};
+
+/// Byte marking a cell whose contents are not yet known.
+pub const UNKNOWN_CHAR: u8 = b'?';
+
+/// Cells that [`deduce`] proved safe or proved to be mines.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Deductions {
+    /// Unknown cells that cannot hold a mine.
+    pub safe: BTreeSet<(usize, usize)>,
+    /// Unknown cells that must hold a mine.
+    pub mines: BTreeSet<(usize, usize)>,
+}
+
+/// One revealed cell: `mines` of its `unknown` neighbors hold a mine.
+struct Constraint {
+    unknown: BTreeSet<(usize, usize)>,
+    mines: usize,
+}
+
+/// Deduces which unknown cells of a partially revealed board are certain.
+///
+/// `'?'` marks an unknown cell, `'*'` a known (flagged) mine, and `' '` or
+/// `'1'`–`'8'` a revealed cell with that many adjacent mines. Every revealed
+/// cell is a constraint on its unknown neighbors, and two rules are applied
+/// until neither finds anything new:
+///
+/// 1. Single cell: if a constraint has no mines left, its unknown neighbors
+///    are safe; if it has as many mines left as unknown neighbors, they are
+///    all mines.
+/// 2. Subset: if one constraint's unknown cells are a subset of another's,
+///    the difference holds exactly the difference of their mine counts, so
+///    rule 1 applies to that difference.
+///
+/// Cells proved in one round feed the next. No further search is done, so
+/// some boards with a unique solution still yield partial results.
+///
+/// # Arguments
+/// * `board` - The partially revealed board, one string per row
+///
+/// # Returns
+/// * `Deductions` - Provably safe and provably mined `(row, col)` cells;
+///   both sets are empty when nothing can be deduced
+///
+/// # Panics
+/// Panics if the board contains a byte other than `'?'`, `'*'`, `' '` or
+/// `'1'`–`'8'`.
+pub fn deduce(board: &[&str]) -> Deductions {
+    let cell_at = |row: usize, col: usize| {
+        board
+            .get(row)
+            .and_then(|row_str| row_str.as_bytes().get(col))
+            .copied()
+    };
+
+    let total_rows = board.len();
+    let total_cols = board.first().map_or(0, |row_str| row_str.len());
+    let mut constraints = Vec::new();
+    for (row, row_str) in board.iter().enumerate() {
+        for (col, &cell) in row_str.as_bytes().iter().enumerate() {
+            let count = match cell {
+                UNKNOWN_CHAR | MINE_CHAR => continue,
+                SPACE_CHAR => 0,
+                b'1'..=b'8' => usize::from(cell - DIGIT_ONE) + 1,
+                _ => panic!("invalid board byte {cell:#04x} at row {row}, column {col}"),
+            };
+
+            let mut unknown = BTreeSet::new();
+            let mut flagged = 0;
+            for (target_row, target_col) in neighbors(row, col, total_rows, total_cols) {
+                match cell_at(target_row, target_col) {
+                    Some(UNKNOWN_CHAR) => {
+                        unknown.insert((target_row, target_col));
+                    }
+                    Some(MINE_CHAR) => flagged += 1,
+                    _ => {}
+                }
+            }
+            if !unknown.is_empty() {
+                constraints.push(Constraint {
+                    unknown,
+                    mines: count.saturating_sub(flagged),
+                });
+            }
+        }
+    }
+
+    let mut deductions = Deductions::default();
+    loop {
+        let found = deductions.safe.len() + deductions.mines.len();
+
+        // Fold cells decided so far into every constraint
+        for constraint in &mut constraints {
+            let newly_mined = constraint
+                .unknown
+                .iter()
+                .filter(|cell| deductions.mines.contains(cell))
+                .count();
+            constraint.mines = constraint.mines.saturating_sub(newly_mined);
+            constraint
+                .unknown
+                .retain(|cell| !deductions.mines.contains(cell) && !deductions.safe.contains(cell));
+        }
+        constraints.retain(|constraint| !constraint.unknown.is_empty());
+
+        // Rule 1: single-cell deductions
+        for constraint in &constraints {
+            settle(&constraint.unknown, constraint.mines, &mut deductions);
+        }
+
+        // Rule 2: subset deductions
+        for smaller in &constraints {
+            for larger in &constraints {
+                if smaller.unknown.len() < larger.unknown.len()
+                    && smaller.unknown.is_subset(&larger.unknown)
+                    && larger.mines >= smaller.mines
+                {
+                    let difference = &larger.unknown - &smaller.unknown;
+                    settle(&difference, larger.mines - smaller.mines, &mut deductions);
+                }
+            }
+        }
+
+        if deductions.safe.len() + deductions.mines.len() == found {
+            return deductions;
+        }
+    }
+}
+
+/// Marks `cells` safe if they hold no mines, or all mined if every one must.
+fn settle(cells: &BTreeSet<(usize, usize)>, mines: usize, deductions: &mut Deductions) {
+    if mines == 0 {
+        deductions.safe.extend(cells.iter().copied());
+    } else if mines == cells.len() {
+        deductions.mines.extend(cells.iter().copied());
+    }
+}
@@ -0,0 +1,67 @@
+use core::fmt;
+
+use crate::{DIGIT_ONE, MINE_CHAR, SPACE_CHAR, annotate};
+
+/// Summary of a minefield's size, mine density and count distribution.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldStats {
+    /// Number of cells on the board, mines included.
+    pub total_cells: usize,
+    /// Number of mine cells.
+    pub mines: usize,
+    /// `mines / total_cells`, or `0.0` for a board with no cells.
+    pub density: f64,
+    /// `adjacency[n]` is how many non-mine cells have exactly `n` adjacent mines.
+    pub adjacency: [usize; 9],
+}
+
+impl fmt::Display for FieldStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} mines in {} cells ({:.1}% density); counts 0-8: {:?}",
+            self.mines,
+            self.total_cells,
+            self.density * 100.0,
+            self.adjacency
+        )
+    }
+}
+
+/// Annotates a minefield and tallies its statistics in one pass over the result.
+///
+/// Useful for tuning generator difficulty: a low density with many zero cells
+/// opens up in a few clicks, while many high counts make for a harder board.
+///
+/// # Arguments
+/// * `minefield` - A slice of string slices representing the minefield
+///
+/// # Returns
+/// * `FieldStats` - Cell and mine totals, density and the adjacency histogram
+///
+/// # Panics
+/// Panics if the minefield is ragged or contains bytes other than `' '` and `'*'`.
+pub fn field_stats(minefield: &[&str]) -> FieldStats {
+    let mut stats = FieldStats {
+        total_cells: 0,
+        mines: 0,
+        density: 0.0,
+        adjacency: [0; 9],
+    };
+
+    for row_str in annotate(minefield) {
+        for &cell in row_str.as_bytes() {
+            stats.total_cells += 1;
+            match cell {
+                MINE_CHAR => stats.mines += 1,
+                SPACE_CHAR => stats.adjacency[0] += 1,
+                digit => stats.adjacency[usize::from(digit - DIGIT_ONE) + 1] += 1,
+            }
+        }
+    }
+
+    if stats.total_cells > 0 {
+        stats.density = stats.mines as f64 / stats.total_cells as f64;
+    }
+    stats
+}
@@ -0,0 +1,116 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::{MINE_CHAR, check_row, expect_valid, update_adjacent_cells};
+
+/// Incremental annotator for minefields that arrive one row at a time.
+///
+/// A row's counts depend on the rows directly above and below it, so each
+/// row is held back until the next one is pushed. The window therefore never
+/// holds more than two rows and memory stays O(width) regardless of height.
+///
+/// ```
+/// use minesweeper::StreamAnnotator;
+///
+/// let mut stream = StreamAnnotator::new();
+/// assert_eq!(stream.push_row(" * "), None);
+/// assert_eq!(stream.push_row("   "), Some("1*1".to_string()));
+/// assert_eq!(stream.finish(), vec!["111".to_string()]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct StreamAnnotator {
+    /// Width fixed by the first row.
+    total_cols: usize,
+    /// Number of rows pushed so far.
+    rows_seen: usize,
+    /// The previous row, annotated except for contributions from the next row.
+    pending: Option<Vec<u8>>,
+}
+
+impl StreamAnnotator {
+    /// Creates an annotator that has not seen any rows yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feeds the next raw row and returns the previous row once it is complete.
+    ///
+    /// # Arguments
+    /// * `row` - The next row of `' '` and `'*'` bytes
+    ///
+    /// # Returns
+    /// * `Some(String)` - The fully annotated previous row
+    /// * `None` - For the first row, which still awaits its lower neighbor
+    ///
+    /// # Panics
+    /// Panics if `row` differs in length from the first row or contains bytes
+    /// other than `' '` and `'*'`.
+    pub fn push_row(&mut self, row: &str) -> Option<String> {
+        let row_bytes = row.as_bytes();
+        if self.rows_seen == 0 {
+            self.total_cols = row_bytes.len();
+        }
+        expect_valid(check_row(
+            self.rows_seen,
+            row_bytes,
+            self.total_cols,
+            MINE_CHAR,
+        ));
+        self.rows_seen += 1;
+
+        let total_cols = self.total_cols;
+        let previous = self.pending.take();
+        let mut window: Vec<Vec<u8>> = previous.into_iter().collect();
+        window.push(row_bytes.to_vec());
+        let current_row = window.len() - 1;
+
+        // Mines in the previous row still owe increments to the new row. The
+        // new row is addressed as a one-row grid whose mine sits at row -1
+        // (usize::MAX), so only the +1 row offsets land in bounds.
+        if let [above, below] = window.as_mut_slice() {
+            for (col_index, &cell) in above.iter().enumerate() {
+                if cell == MINE_CHAR {
+                    update_adjacent_cells(
+                        core::slice::from_mut(below),
+                        usize::MAX,
+                        col_index,
+                        1,
+                        total_cols,
+                        MINE_CHAR,
+                    );
+                }
+            }
+        }
+
+        // Mines in the new row update both rows in the window
+        let window_rows = window.len();
+        for (col_index, &cell) in row_bytes.iter().enumerate() {
+            if cell == MINE_CHAR {
+                update_adjacent_cells(
+                    &mut window,
+                    current_row,
+                    col_index,
+                    window_rows,
+                    total_cols,
+                    MINE_CHAR,
+                );
+            }
+        }
+
+        self.pending = window.pop();
+        window
+            .pop()
+            .map(|row_bytes| String::from_utf8(row_bytes).unwrap())
+    }
+
+    /// Flushes the final row, which has no lower neighbor.
+    ///
+    /// # Returns
+    /// * `Vec<String>` - The last annotated row, or nothing if no rows were pushed
+    pub fn finish(self) -> Vec<String> {
+        self.pending
+            .into_iter()
+            .map(|row_bytes| String::from_utf8(row_bytes).unwrap())
+            .collect()
+    }
+}
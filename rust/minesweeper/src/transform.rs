@@ -0,0 +1,85 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Checks that a board is rectangular ASCII and returns its dimensions.
+fn dimensions(minefield: &[&str]) -> (usize, usize) {
+    let total_cols = minefield.first().map_or(0, |row_str| row_str.len());
+    for (row_index, row_str) in minefield.iter().enumerate() {
+        assert!(
+            row_str.len() == total_cols && row_str.is_ascii(),
+            "invalid minefield: row {row_index} is not {total_cols} ASCII bytes wide"
+        );
+    }
+    (minefield.len(), total_cols)
+}
+
+/// Builds a `rows × cols` board where cell `(r, c)` is `source(r, c)`.
+fn build(rows: usize, cols: usize, source: impl Fn(usize, usize) -> u8) -> Vec<String> {
+    (0..rows)
+        .map(|row| {
+            let row_bytes = (0..cols).map(|col| source(row, col)).collect();
+            // Every byte comes from an ASCII input cell
+            String::from_utf8(row_bytes).unwrap()
+        })
+        .collect()
+}
+
+/// Swaps rows and columns: cell `(r, c)` moves to `(c, r)`.
+///
+/// A board of zero-width rows transposes to an empty board.
+///
+/// # Panics
+/// Panics if the board is not rectangular ASCII.
+pub fn transpose(minefield: &[&str]) -> Vec<String> {
+    let (total_rows, total_cols) = dimensions(minefield);
+    build(total_cols, total_rows, |row, col| {
+        minefield[col].as_bytes()[row]
+    })
+}
+
+/// Rotates the board a quarter turn clockwise.
+///
+/// # Panics
+/// Panics if the board is not rectangular ASCII.
+pub fn rotate_cw(minefield: &[&str]) -> Vec<String> {
+    let (total_rows, total_cols) = dimensions(minefield);
+    build(total_cols, total_rows, |row, col| {
+        minefield[total_rows - 1 - col].as_bytes()[row]
+    })
+}
+
+/// Rotates the board a quarter turn counter-clockwise.
+///
+/// # Panics
+/// Panics if the board is not rectangular ASCII.
+pub fn rotate_ccw(minefield: &[&str]) -> Vec<String> {
+    let (total_rows, total_cols) = dimensions(minefield);
+    build(total_cols, total_rows, |row, col| {
+        minefield[col].as_bytes()[total_cols - 1 - row]
+    })
+}
+
+/// Mirrors the board left-to-right.
+///
+/// # Panics
+/// Panics if the board is not rectangular ASCII.
+pub fn flip_horizontal(minefield: &[&str]) -> Vec<String> {
+    let (total_rows, total_cols) = dimensions(minefield);
+    build(total_rows, total_cols, |row, col| {
+        minefield[row].as_bytes()[total_cols - 1 - col]
+    })
+}
+
+/// Annotates a board stored column-major: each slice is one column.
+///
+/// The 8-cell neighborhood is symmetric under transposition, so annotating
+/// the columns directly gives the same counts as transposing, annotating and
+/// transposing back, without the two copies. Each returned string is one
+/// annotated column, in input order.
+///
+/// # Panics
+/// Panics if the columns differ in length or contain bytes other than `' '`
+/// and `'*'`.
+pub fn annotate_colmajor(cols: &[&str]) -> Vec<String> {
+    crate::annotate(cols)
+}
@@ -0,0 +1,221 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::{
+    DIGIT_ONE, MINE_CHAR, SPACE_CHAR, count_adjacent_mines, deannotate, expect_valid, validate,
+};
+
+/// Error returned by [`diff`] when the two boards are not the same shape.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiffError {
+    /// The boards have different numbers of rows.
+    RowCount { a: usize, b: usize },
+    /// Row `row` has a different byte length in each board.
+    RowLength { row: usize, a: usize, b: usize },
+}
+
+impl fmt::Display for DiffError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DiffError::RowCount { a, b } => {
+                write!(f, "boards have {a} and {b} rows")
+            }
+            DiffError::RowLength { row, a, b } => {
+                write!(
+                    f,
+                    "row {row} has length {a} in one board and {b} in the other"
+                )
+            }
+        }
+    }
+}
+
+impl core::error::Error for DiffError {}
+
+/// Annotates a minefield the obvious way, as a reference for [`crate::annotate`].
+///
+/// [`crate::annotate`] starts from each mine and bumps its neighbors with
+/// branchless byte arithmetic. This version does the opposite, and the
+/// simplest thing: it visits every empty cell and counts the mines among its
+/// 8 neighbors one by one. It does up to 8 lookups per cell instead of per
+/// mine, so it is slower on sparse boards, but each step is easy to check by
+/// hand, which makes it the baseline the optimized paths are tested against.
+///
+/// # Arguments
+/// * `minefield` - A slice of string slices representing the minefield
+///
+/// # Returns
+/// * `Vec<String>` - The annotated minefield with mine counts
+///
+/// # Panics
+/// Panics if the minefield is ragged or contains bytes other than `' '` and `'*'`.
+pub fn annotate_naive(minefield: &[&str]) -> Vec<String> {
+    expect_valid(validate(minefield));
+    let rows = minefield.len();
+    let cols = minefield.first().map_or(0, |row_str| row_str.len());
+    let is_mine = |row: usize, col: usize| minefield[row].as_bytes()[col] == MINE_CHAR;
+
+    let mut annotated = Vec::with_capacity(rows);
+    for row in 0..rows {
+        let mut line = String::with_capacity(cols);
+        for col in 0..cols {
+            // Mines are copied through unchanged
+            if is_mine(row, col) {
+                line.push('*');
+                continue;
+            }
+
+            // Look at the 3x3 square around the cell, skipping the cell
+            // itself and anything past the edge of the board
+            let mut mines = 0;
+            for neighbor_row in row.saturating_sub(1)..=row + 1 {
+                for neighbor_col in col.saturating_sub(1)..=col + 1 {
+                    let is_self = neighbor_row == row && neighbor_col == col;
+                    let on_board = neighbor_row < rows && neighbor_col < cols;
+                    if !is_self && on_board && is_mine(neighbor_row, neighbor_col) {
+                        mines += 1;
+                    }
+                }
+            }
+
+            // Zero stays blank; 1 to 8 become the digits '1' to '8'
+            if mines == 0 {
+                line.push(' ');
+            } else {
+                line.push(char::from(b'0' + mines));
+            }
+        }
+        annotated.push(line);
+    }
+    annotated
+}
+
+/// Checks an annotated board against a reference recount of its raw field.
+///
+/// See [`find_mismatch`] for how cells are compared.
+///
+/// # Arguments
+/// * `raw` - The unannotated minefield
+/// * `annotated` - The board claimed to be its annotation
+///
+/// # Returns
+/// * `bool` - `true` if every cell matches
+pub fn verify(raw: &[&str], annotated: &[&str]) -> bool {
+    find_mismatch(raw, annotated).is_none()
+}
+
+/// Cheaply guesses whether a board has already been annotated.
+///
+/// Returns `true` as soon as any cell is a count digit `'1'`–`'8'`, which a
+/// raw minefield never contains. Nothing is recounted, so a board with stray
+/// digits is reported as annotated, and a board with no counts at all (no
+/// mines, or mines only) is reported as raw even though annotating it would
+/// change nothing. Use [`is_annotated_strict`] when the answer must be exact.
+///
+/// # Arguments
+/// * `board` - The board to inspect
+///
+/// # Returns
+/// * `bool` - `true` if any cell holds a count digit
+pub fn is_annotated(board: &[&str]) -> bool {
+    board
+        .iter()
+        .any(|row_str| row_str.bytes().any(|cell| matches!(cell, b'1'..=b'8')))
+}
+
+/// Checks that a board is exactly the annotation of its own mines.
+///
+/// The digits are stripped with [`deannotate`], the result must be a valid
+/// minefield, and every cell is then recounted with [`find_mismatch`]. Boards
+/// that contain no counts are their own annotation, so an all-blank board
+/// passes here while [`is_annotated`] says `false`.
+///
+/// # Arguments
+/// * `board` - The board to inspect
+///
+/// # Returns
+/// * `bool` - `true` if annotating the stripped board reproduces `board`
+pub fn is_annotated_strict(board: &[&str]) -> bool {
+    let raw = deannotate(board);
+    let raw: Vec<&str> = raw.iter().map(String::as_str).collect();
+    validate(&raw).is_ok() && find_mismatch(&raw, board).is_none()
+}
+
+/// Finds the first cell where `annotated` disagrees with a naive recount.
+///
+/// This deliberately avoids the optimized branchless path: every non-mine
+/// cell of `raw` is recounted by looking at its 8 neighbors directly
+/// (O(R × C × 8)), making it a reference for cross-checking during fuzzing.
+/// A cell present in one board but missing from the other (differing
+/// dimensions) also counts as a mismatch.
+///
+/// # Arguments
+/// * `raw` - The unannotated minefield
+/// * `annotated` - The board claimed to be its annotation
+///
+/// # Returns
+/// * `Some((row, col))` - The first mismatching coordinate in row-major order
+/// * `None` - If the boards agree everywhere
+pub fn find_mismatch(raw: &[&str], annotated: &[&str]) -> Option<(usize, usize)> {
+    let total_rows = raw.len().max(annotated.len());
+    for row in 0..total_rows {
+        let raw_row = raw.get(row).map_or(&[][..], |row_str| row_str.as_bytes());
+        let annotated_row = annotated
+            .get(row)
+            .map_or(&[][..], |row_str| row_str.as_bytes());
+
+        for col in 0..raw_row.len().max(annotated_row.len()) {
+            let expected = raw_row.get(col).map(|&cell| match cell {
+                MINE_CHAR => MINE_CHAR,
+                _ => match count_adjacent_mines(raw, row, col) {
+                    0 => SPACE_CHAR,
+                    count => DIGIT_ONE + count - 1,
+                },
+            });
+            if expected != annotated_row.get(col).copied() {
+                return Some((row, col));
+            }
+        }
+    }
+    None
+}
+
+/// Lists every cell where two boards of the same shape differ.
+///
+/// Works on any boards, raw or annotated, so it can show what changed
+/// between two generated fields or between two versions of [`crate::annotate`].
+///
+/// # Arguments
+/// * `a` - The first board
+/// * `b` - The second board
+///
+/// # Returns
+/// * `Ok(Vec<(usize, usize, u8, u8)>)` - `(row, col, a_byte, b_byte)` for each
+///   differing cell in row-major order; empty if the boards are equal
+/// * `Err(DiffError)` - If the row counts or any row's lengths differ
+pub fn diff(a: &[&str], b: &[&str]) -> Result<Vec<(usize, usize, u8, u8)>, DiffError> {
+    if a.len() != b.len() {
+        return Err(DiffError::RowCount {
+            a: a.len(),
+            b: b.len(),
+        });
+    }
+
+    let mut differences = Vec::new();
+    for (row, (a_row, b_row)) in a.iter().zip(b).enumerate() {
+        if a_row.len() != b_row.len() {
+            return Err(DiffError::RowLength {
+                row,
+                a: a_row.len(),
+                b: b_row.len(),
+            });
+        }
+        for (col, (&a_byte, &b_byte)) in a_row.as_bytes().iter().zip(b_row.as_bytes()).enumerate() {
+            if a_byte != b_byte {
+                differences.push((row, col, a_byte, b_byte));
+            }
+        }
+    }
+    Ok(differences)
+}
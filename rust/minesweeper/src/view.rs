@@ -0,0 +1,84 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::{MINE_CHAR, annotate_grid, expect_valid, grid_to_strings};
+
+/// An annotated minefield kept as bytes, readable without building strings.
+///
+/// Produced by [`annotate_view`]. Rows are only converted to owned `String`s
+/// when [`GridView::into_strings`] is called.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GridView {
+    grid: Vec<Vec<u8>>,
+}
+
+impl GridView {
+    /// Returns the number of rows.
+    pub fn rows(&self) -> usize {
+        self.grid.len()
+    }
+
+    /// Returns the number of columns (0 for an empty grid).
+    pub fn cols(&self) -> usize {
+        self.grid.first().map_or(0, Vec::len)
+    }
+
+    /// Returns the annotated byte at `(row, col)`.
+    ///
+    /// # Panics
+    /// Panics if `(row, col)` is out of range.
+    pub fn cell(&self, row: usize, col: usize) -> u8 {
+        self.grid[row][col]
+    }
+
+    /// Borrows row `row` as a string slice.
+    ///
+    /// # Panics
+    /// Panics if `row` is out of range.
+    pub fn row_str(&self, row: usize) -> &str {
+        // The grid only ever holds ASCII, so this cannot fail
+        core::str::from_utf8(&self.grid[row]).unwrap()
+    }
+
+    /// Converts the view into the same owned output as [`crate::annotate`].
+    pub fn into_strings(self) -> Vec<String> {
+        grid_to_strings(self.grid)
+    }
+}
+
+/// Annotates a minefield into a [`GridView`] instead of a `Vec<String>`.
+///
+/// # Arguments
+/// * `minefield` - A slice of string slices representing the minefield
+///
+/// # Returns
+/// * `GridView` - The annotated cells
+///
+/// # Panics
+/// Panics if the minefield is ragged or contains bytes other than `' '` and `'*'`.
+pub fn annotate_view(minefield: &[&str]) -> GridView {
+    GridView {
+        grid: expect_valid(annotate_grid(minefield, MINE_CHAR)),
+    }
+}
+
+/// Returns the byte at `(row, col)` of an annotated board, or `None` if out of range.
+///
+/// The output of [`crate::annotate`] is always ASCII, so a column index is
+/// also a byte offset and no UTF-8 decoding is needed. For boards that may
+/// hold other characters `col` is still treated as a byte offset.
+///
+/// # Arguments
+/// * `board` - An annotated board, as returned by [`crate::annotate`]
+/// * `row` - Row index (0-based)
+/// * `col` - Column index (0-based)
+///
+/// # Returns
+/// * `Some(u8)` - The cell's byte
+/// * `None` - If `row` or `col` is past the edge of the board
+pub fn cell_at(board: &[String], row: usize, col: usize) -> Option<u8> {
+    board
+        .get(row)
+        .and_then(|row_str| row_str.as_bytes().get(col))
+        .copied()
+}
@@ -0,0 +1,34 @@
+use alloc::string::{String, ToString};
+
+use wasm_bindgen::JsValue;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::try_annotate_str;
+
+/// Annotates a newline-joined board, for calling from JavaScript.
+///
+/// `&[&str]` does not cross the wasm-bindgen boundary, so the board travels
+/// as one newline-joined string and is handled by [`try_annotate_str`],
+/// `"\r\n"` line endings and the optional final terminator included.
+///
+/// # Building
+/// The manifest keeps the default `rlib` crate type, so ask for a `cdylib`
+/// when building for the browser and run `wasm-bindgen` on the result:
+///
+/// ```text
+/// cargo rustc --release --target wasm32-unknown-unknown --features wasm --crate-type cdylib
+/// wasm-bindgen --target web --out-dir pkg target/wasm32-unknown-unknown/release/minesweeper.wasm
+/// ```
+///
+/// # Arguments
+/// * `input` - The minefield rows joined with `'\n'`
+///
+/// # Returns
+/// * `Ok(String)` - The annotated rows joined with `'\n'`
+/// * `Err(JsValue)` - A string describing why the board is ragged or holds
+///   bytes other than `' '` and `'*'`; JavaScript sees it as a thrown error
+///   instead of a trapped module
+#[wasm_bindgen]
+pub fn annotate_js(input: &str) -> Result<String, JsValue> {
+    try_annotate_str(input).map_err(|err| JsValue::from_str(&err.to_string()))
+}
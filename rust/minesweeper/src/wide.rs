@@ -0,0 +1,257 @@
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{
+    AnnotateError, MINE_CHAR, SPACE_CHAR, expect_valid, neighbors, parse_grid, scan_mines,
+};
+
+/// Largest count representable by the wide encoding (`'Z'`).
+pub const MAX_WIDE_COUNT: u32 = 35;
+
+/// Encodes a neighbor count as a single output byte.
+///
+/// The wide encoding is always exactly one byte per cell, so rows can be
+/// split by byte offset just like the output of [`crate::annotate`]:
+///
+/// | count  | byte          |
+/// |--------|---------------|
+/// | 0      | `' '`         |
+/// | 1–9    | `'1'`–`'9'`   |
+/// | 10–35  | `'A'`–`'Z'`   |
+///
+/// Counts 1–8 are therefore identical to the standard digits, and letters
+/// continue the hexadecimal convention (`'A'` = 10 … `'F'` = 15) up to base 36.
+///
+/// # Arguments
+/// * `count` - The number of adjacent mines (or accumulated weight)
+///
+/// # Returns
+/// * `u8` - The ASCII byte for `count`
+///
+/// # Panics
+/// Panics if `count` exceeds [`MAX_WIDE_COUNT`].
+pub fn wide_count_byte(count: u32) -> u8 {
+    match count {
+        0 => SPACE_CHAR,
+        1..=9 => b'0' + count as u8,
+        10..=MAX_WIDE_COUNT => b'A' + (count - 10) as u8,
+        _ => panic!("count {count} exceeds the wide encoding maximum of {MAX_WIDE_COUNT}"),
+    }
+}
+
+/// Annotates a minefield using the wide count encoding.
+///
+/// With the standard 8-cell neighborhood no count can exceed 8, so the output
+/// is identical to [`crate::annotate`]. The encoding itself is described on
+/// [`wide_count_byte`] and is shared by neighborhoods that can exceed 9.
+///
+/// # Arguments
+/// * `minefield` - A slice of string slices representing the minefield
+///
+/// # Returns
+/// * `Vec<String>` - The annotated minefield, one byte per cell
+///
+/// # Panics
+/// Panics if the minefield is ragged or contains bytes other than `' '` and `'*'`.
+pub fn annotate_wide(minefield: &[&str]) -> Vec<String> {
+    let grid = expect_valid(parse_grid(minefield, MINE_CHAR));
+    let total_rows = grid.len();
+    let total_cols = grid.first().map_or(0, Vec::len);
+    let mut counts = vec![vec![0u32; total_cols]; total_rows];
+
+    scan_mines(minefield, MINE_CHAR, |mine_row, mine_col| {
+        for row_offset in -1..=1isize {
+            for col_offset in -1..=1isize {
+                let target_row = mine_row.wrapping_add_signed(row_offset);
+                let target_col = mine_col.wrapping_add_signed(col_offset);
+                if (row_offset, col_offset) != (0, 0)
+                    && target_row < total_rows
+                    && target_col < total_cols
+                {
+                    counts[target_row][target_col] += 1;
+                }
+            }
+        }
+    });
+
+    render_wide(&grid, &counts, MINE_CHAR)
+}
+
+/// Annotates a minefield counting mines within a Chebyshev radius.
+///
+/// Each non-mine cell counts the mines in the `(2 × radius + 1)`-wide square
+/// window centered on it, clipped to the board. `radius` 1 is the standard
+/// 8-cell neighborhood and matches [`crate::annotate`]; `radius` 0 counts
+/// nothing. Counts use the wide encoding described on [`wide_count_byte`].
+///
+/// A 2D prefix sum of mine positions gives every window's total in constant
+/// time, so the cost is O(R × C) whatever the radius.
+///
+/// # Arguments
+/// * `minefield` - A slice of string slices representing the minefield
+/// * `radius` - The Chebyshev distance a mine reaches
+///
+/// # Returns
+/// * `Vec<String>` - The annotated minefield, one byte per cell
+///
+/// # Panics
+/// Panics if the minefield is ragged or contains bytes other than `' '` and
+/// `'*'`, or if any count exceeds [`MAX_WIDE_COUNT`] (possible from radius 3).
+pub fn annotate_radius(minefield: &[&str], radius: usize) -> Vec<String> {
+    let grid = expect_valid(parse_grid(minefield, MINE_CHAR));
+    let total_rows = grid.len();
+    let total_cols = grid.first().map_or(0, Vec::len);
+
+    // prefix[r][c] is the number of mines in rows 0..r and columns 0..c
+    let mut prefix = vec![vec![0u32; total_cols + 1]; total_rows + 1];
+    for (row, row_bytes) in grid.iter().enumerate() {
+        for (col, &cell) in row_bytes.iter().enumerate() {
+            prefix[row + 1][col + 1] = prefix[row][col + 1] + prefix[row + 1][col]
+                - prefix[row][col]
+                + u32::from(cell == MINE_CHAR);
+        }
+    }
+
+    let counts: Vec<Vec<u32>> = (0..total_rows)
+        .map(|row| {
+            let (top, bottom) = (
+                row.saturating_sub(radius),
+                row.saturating_add(radius).saturating_add(1).min(total_rows),
+            );
+            (0..total_cols)
+                .map(|col| {
+                    let (left, right) = (
+                        col.saturating_sub(radius),
+                        col.saturating_add(radius).saturating_add(1).min(total_cols),
+                    );
+                    // Mines are rendered as-is, so their own cell never needs excluding
+                    prefix[bottom][right] + prefix[top][left]
+                        - prefix[top][right]
+                        - prefix[bottom][left]
+                })
+                .collect()
+        })
+        .collect();
+
+    render_wide(&grid, &counts, MINE_CHAR)
+}
+
+/// Annotates a minefield whose mine types contribute different weights.
+///
+/// Each `(mine, weight)` pair names a mine byte and how much it adds to every
+/// neighbor, e.g. `&[(b'*', 1), (b'#', 2)]`. Non-mine cells hold the sum of
+/// their neighbors' weights in the wide encoding described on
+/// [`wide_count_byte`], so sums from 10 up render as letters. Mine cells keep
+/// their own byte.
+///
+/// # Arguments
+/// * `minefield` - A slice of string slices representing the minefield
+/// * `weights` - The mine bytes and their contributions
+///
+/// # Returns
+/// * `Vec<String>` - The annotated minefield, one byte per cell
+///
+/// # Panics
+/// Panics if a mine byte is `' '`, an ASCII letter or digit (both are count
+/// glyphs in the wide encoding) or not ASCII, if a mine byte is listed twice,
+/// if the minefield is ragged or contains bytes other than `' '` and the
+/// listed mines, or if any sum exceeds [`MAX_WIDE_COUNT`].
+pub fn annotate_weighted(minefield: &[&str], weights: &[(u8, u8)]) -> Vec<String> {
+    let mut weight_of = [None; 256];
+    for &(mine, weight) in weights {
+        assert!(
+            mine.is_ascii() && mine != SPACE_CHAR && !mine.is_ascii_alphanumeric(),
+            "mine marker {:?} collides with empty cells or counts",
+            mine as char
+        );
+        assert!(
+            weight_of[usize::from(mine)]
+                .replace(u32::from(weight))
+                .is_none(),
+            "mine marker {:?} is listed more than once",
+            mine as char
+        );
+    }
+
+    let total_rows = minefield.len();
+    let total_cols = minefield.first().map_or(0, |row_str| row_str.len());
+    // Every row is checked before `counts` is sized from the first row's width
+    for (row, row_str) in minefield.iter().enumerate() {
+        if row_str.len() != total_cols {
+            expect_valid(Err(AnnotateError::RaggedRows {
+                row,
+                expected: total_cols,
+                found: row_str.len(),
+            }))
+        }
+        let row_bytes = row_str.as_bytes();
+        if let Some(col) = row_bytes
+            .iter()
+            .position(|&byte| byte != SPACE_CHAR && weight_of[usize::from(byte)].is_none())
+        {
+            expect_valid(Err(AnnotateError::InvalidChar {
+                row,
+                col,
+                byte: row_bytes[col],
+            }))
+        }
+    }
+
+    let mut counts = vec![vec![0u32; total_cols]; total_rows];
+    for (row, row_str) in minefield.iter().enumerate() {
+        for (col, &byte) in row_str.as_bytes().iter().enumerate() {
+            // Each mine adds its weight instead of a fixed 1
+            if let Some(weight) = weight_of[usize::from(byte)] {
+                for (target_row, target_col) in neighbors(row, col, total_rows, total_cols) {
+                    counts[target_row][target_col] += weight;
+                }
+            }
+        }
+    }
+
+    minefield
+        .iter()
+        .zip(&counts)
+        .map(|(row_str, row_counts)| {
+            let rendered = row_str
+                .bytes()
+                .zip(row_counts)
+                .map(|(cell, &count)| {
+                    if cell == SPACE_CHAR {
+                        wide_count_byte(count)
+                    } else {
+                        cell
+                    }
+                })
+                .collect();
+            String::from_utf8(rendered).unwrap()
+        })
+        .collect()
+}
+
+/// Renders per-cell counts with the wide encoding, keeping mines as-is.
+///
+/// # Arguments
+/// * `grid` - The raw minefield bytes, used to locate mines
+/// * `counts` - Accumulated counts with the same dimensions as `grid`
+/// * `mine` - The byte that marks a mine
+pub(crate) fn render_wide(grid: &[Vec<u8>], counts: &[Vec<u32>], mine: u8) -> Vec<String> {
+    grid.iter()
+        .zip(counts)
+        .map(|(row_bytes, row_counts)| {
+            let rendered = row_bytes
+                .iter()
+                .zip(row_counts)
+                .map(|(&cell, &count)| {
+                    if cell == mine {
+                        cell
+                    } else {
+                        wide_count_byte(count)
+                    }
+                })
+                .collect();
+            String::from_utf8(rendered).unwrap()
+        })
+        .collect()
+}
@@ -188,3 +188,2072 @@ fn large_minefield() {
     let actual = annotate(input);
     assert_eq!(actual, expected);
 }
+
+#[test]
+fn try_annotate_matches_annotate_on_valid_input() {
+    let input = &[" * * "];
+    let expected = &["1*2*1"];
+    let actual = try_annotate(input).unwrap();
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn try_annotate_rejects_ragged_rows() {
+    let input = &["**", "*"];
+    let actual = try_annotate(input);
+    assert_eq!(
+        actual,
+        Err(AnnotateError::RaggedRows {
+            row: 1,
+            expected: 2,
+            found: 1,
+        })
+    );
+}
+
+#[test]
+fn try_annotate_rejects_invalid_char() {
+    let input = &["  ", " x"];
+    let actual = try_annotate(input);
+    assert_eq!(
+        actual,
+        Err(AnnotateError::InvalidChar {
+            row: 1,
+            col: 1,
+            byte: b'x',
+        })
+    );
+}
+
+#[test]
+#[should_panic(expected = "invalid minefield")]
+fn annotate_panics_on_ragged_rows() {
+    annotate(&["**", "*"]);
+}
+
+#[test]
+fn annotate_with_custom_mine_char() {
+    #[rustfmt::skip]
+    let (input, expected) = (&[
+        "   ",
+        " X ",
+        "  X",
+    ], &[
+        "111",
+        "1X2",
+        "12X",
+    ]);
+    let actual = annotate_with(input, b'X');
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn annotate_with_star_matches_annotate() {
+    let input = &["*   *", "  *  "];
+    assert_eq!(annotate_with(input, b'*'), annotate(input));
+}
+
+#[test]
+#[should_panic(expected = "collides")]
+fn annotate_with_digit_mine_panics() {
+    annotate_with(&[" 3 "], b'3');
+}
+
+#[test]
+fn toroidal_corner_mine_wraps_to_all_neighbors() {
+    #[rustfmt::skip]
+    let (input, expected) = (&[
+        "*  ",
+        "   ",
+        "   ",
+    ], &[
+        "*11",
+        "111",
+        "111",
+    ]);
+    let actual = annotate_toroidal(input);
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn toroidal_single_mine_does_not_count_itself() {
+    let input = &["*"];
+    let expected = &["*"];
+    let actual = annotate_toroidal(input);
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn toroidal_narrow_board_counts_each_neighbor_once() {
+    let input = &["* "];
+    let expected = &["*1"];
+    let actual = annotate_toroidal(input);
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn toroidal_edges_wrap() {
+    #[rustfmt::skip]
+    let (input, expected) = (&[
+        "*   ",
+        "    ",
+        "    ",
+        "   *",
+    ], &[
+        "*112",
+        "11 1",
+        "1 11",
+        "211*",
+    ]);
+    let actual = annotate_toroidal(input);
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn annotate_chars_matches_annotate_on_ascii() {
+    let input = &["  *  ", "  *  ", "*****"];
+    assert_eq!(annotate_chars(input), annotate(input));
+}
+
+#[test]
+fn annotate_chars_with_multibyte_cells() {
+    #[rustfmt::skip]
+    let (input, expected) = (&[
+        "·💣·",
+        "···",
+        "··💣",
+    ], &[
+        "1💣1",
+        "122",
+        "·1💣",
+    ]);
+    let actual = annotate_chars_with(input, '·', '💣');
+    assert_eq!(actual, expected);
+}
+
+#[test]
+#[should_panic(expected = "invalid minefield")]
+fn annotate_chars_rejects_ragged_rows() {
+    annotate_chars_with(&["··", "·"], '·', '*');
+}
+
+#[test]
+fn annotate_in_place_matches_annotate() {
+    #[rustfmt::skip]
+    let input = &[
+        " *  * ",
+        "  *   ",
+        "    * ",
+        "   * *",
+        " *  * ",
+        "      ",
+    ];
+    let mut grid: Vec<Vec<u8>> = input.iter().map(|row| row.as_bytes().to_vec()).collect();
+    annotate_in_place(&mut grid);
+    let actual: Vec<String> = grid
+        .into_iter()
+        .map(|row| String::from_utf8(row).unwrap())
+        .collect();
+    assert_eq!(actual, annotate(input));
+}
+
+#[test]
+fn annotate_in_place_empty_grid() {
+    let mut grid: Vec<Vec<u8>> = vec![];
+    annotate_in_place(&mut grid);
+    assert!(grid.is_empty());
+}
+
+#[test]
+fn count_adjacent_mines_matches_annotated_digit() {
+    #[rustfmt::skip]
+    let input = &[
+        "***",
+        "* *",
+        "***",
+    ];
+    assert_eq!(count_adjacent_mines(input, 1, 1), 8);
+    assert_eq!(count_adjacent_mines(input, 0, 0), 2);
+}
+
+#[test]
+fn count_adjacent_mines_ignores_the_cell_itself() {
+    let input = &[" * "];
+    assert_eq!(count_adjacent_mines(input, 0, 1), 0);
+    assert_eq!(count_adjacent_mines(input, 0, 2), 1);
+}
+
+#[test]
+fn count_adjacent_mines_out_of_range_is_zero() {
+    let input = &["**", "**"];
+    assert_eq!(count_adjacent_mines(input, 2, 0), 0);
+    assert_eq!(count_adjacent_mines(input, 0, 2), 0);
+    assert_eq!(count_adjacent_mines(&[], 0, 0), 0);
+}
+
+#[test]
+fn generate_places_exact_mine_count() {
+    let field = generate(8, 10, 17, 42);
+    assert_eq!(field.len(), 8);
+    assert!(field.iter().all(|row| row.len() == 10));
+    let mines: usize = field.iter().map(|row| row.matches('*').count()).sum();
+    assert_eq!(mines, 17);
+    assert!(
+        field
+            .iter()
+            .all(|row| row.bytes().all(|b| b == b' ' || b == b'*'))
+    );
+}
+
+#[test]
+fn generate_is_deterministic_for_a_seed() {
+    assert_eq!(generate(5, 5, 6, 7), generate(5, 5, 6, 7));
+    assert_ne!(generate(5, 5, 6, 7), generate(5, 5, 6, 8));
+}
+
+#[test]
+fn generate_full_and_empty_fields() {
+    assert_eq!(generate(2, 3, 6, 1), vec!["***", "***"]);
+    assert_eq!(generate(2, 3, 0, 1), vec!["   ", "   "]);
+    assert_eq!(generate(2, 0, 0, 1), vec!["", ""]);
+}
+
+#[test]
+#[should_panic(expected = "cannot place")]
+fn generate_rejects_too_many_mines() {
+    generate(2, 2, 5, 0);
+}
+
+#[test]
+#[should_panic(expected = "more cells than usize can index")]
+fn generate_rejects_overflowing_dimensions() {
+    generate(usize::MAX, 2, 0, 0);
+}
+
+#[test]
+fn deannotate_strips_counts() {
+    let input = &["1*22*1", "12*322"];
+    let expected = &[" *  * ", "  *   "];
+    let actual = deannotate(input);
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn deannotate_round_trips_through_annotate() {
+    #[rustfmt::skip]
+    let annotated = &[
+        " 2*2 ",
+        "25*52",
+        "*****",
+        "25*52",
+        " 2*2 ",
+    ];
+    let raw = deannotate(annotated);
+    let raw_rows: Vec<&str> = raw.iter().map(String::as_str).collect();
+    assert_eq!(annotate(&raw_rows), annotated);
+}
+
+#[test]
+fn deannotate_empty() {
+    assert!(deannotate(&[]).is_empty());
+}
+
+#[test]
+fn annotate_wide_matches_annotate_for_eight_neighbors() {
+    #[rustfmt::skip]
+    let input = &[
+        "***",
+        "* *",
+        "***",
+        "   ",
+    ];
+    assert_eq!(annotate_wide(input), annotate(input));
+}
+
+#[test]
+fn wide_count_byte_encoding() {
+    assert_eq!(wide_count_byte(0), b' ');
+    assert_eq!(wide_count_byte(8), b'8');
+    assert_eq!(wide_count_byte(9), b'9');
+    assert_eq!(wide_count_byte(10), b'A');
+    assert_eq!(wide_count_byte(15), b'F');
+    assert_eq!(wide_count_byte(MAX_WIDE_COUNT), b'Z');
+}
+
+#[test]
+#[should_panic(expected = "exceeds")]
+fn wide_count_byte_overflow_panics() {
+    wide_count_byte(MAX_WIDE_COUNT + 1);
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn annotate_parallel_matches_serial_across_band_boundaries() {
+    // A fixed 4-thread pool so the board splits into bands even on one CPU:
+    // bands are max(ceil(rows / 4), 64) rows, so 130 rows gives 64 + 64 + 2,
+    // 200 gives 64 × 3 + 8 and 257 gives 65 × 3 + 62
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(4)
+        .build()
+        .unwrap();
+    pool.install(|| {
+        for (rows, cols, seed) in [
+            (1, 1, 0),
+            (3, 7, 1),
+            (130, 17, 2),
+            (200, 9, 4),
+            (257, 40, 3),
+        ] {
+            let field = generate(rows, cols, rows * cols / 4, seed);
+            let input: Vec<&str> = field.iter().map(String::as_str).collect();
+            assert_eq!(annotate_parallel(&input), annotate(&input), "{rows}x{cols}");
+        }
+        assert!(annotate_parallel(&[]).is_empty());
+    });
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn annotate_parallel_counts_mines_on_band_edges() {
+    // Mines on the last row of each 64-row band and the first row of the next,
+    // so every count next to a boundary needs the halo row
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(4)
+        .build()
+        .unwrap();
+    let mut field = vec![" ".repeat(5); 200];
+    for row in [63, 64, 127, 128, 191, 192, 199] {
+        field[row] = "* * *".to_string();
+    }
+    let input: Vec<&str> = field.iter().map(String::as_str).collect();
+    pool.install(|| assert_eq!(annotate_parallel(&input), annotate(&input)));
+}
+
+#[test]
+fn validate_accepts_well_formed_fields() {
+    assert_eq!(validate(&[]), Ok(()));
+    assert_eq!(validate(&["", ""]), Ok(()));
+    assert_eq!(validate(&[" * ", "***"]), Ok(()));
+}
+
+#[test]
+fn validate_reports_ragged_row() {
+    assert_eq!(
+        validate(&["   ", "   ", "    "]),
+        Err(ValidationError::RaggedRows {
+            row: 2,
+            expected: 3,
+            found: 4,
+        })
+    );
+}
+
+#[test]
+fn validate_reports_bad_byte_location() {
+    assert_eq!(
+        validate(&["* *", "*1*"]),
+        Err(ValidationError::InvalidChar {
+            row: 1,
+            col: 1,
+            byte: b'1',
+        })
+    );
+}
+
+#[test]
+fn annotate_generic_with_byte_cells_matches_annotate() {
+    let input = &[" *  * ", "  *   ", "    * "];
+    let grid: Vec<Vec<ByteCell>> = input
+        .iter()
+        .map(|row| row.bytes().map(ByteCell).collect())
+        .collect();
+    let actual: Vec<String> = annotate_generic(&grid)
+        .into_iter()
+        .map(|row| String::from_utf8(row.into_iter().map(|cell| cell.0).collect()).unwrap())
+        .collect();
+    assert_eq!(actual, annotate(input));
+}
+
+#[test]
+fn annotate_generic_with_custom_enum() {
+    #[derive(Debug, Clone, PartialEq)]
+    enum Tile {
+        Mine,
+        Unknown,
+        Count(u8),
+    }
+
+    impl Cell for Tile {
+        fn is_mine(&self) -> bool {
+            *self == Tile::Mine
+        }
+
+        fn with_count(count: u8) -> Self {
+            Tile::Count(count)
+        }
+    }
+
+    let grid = vec![vec![Tile::Mine, Tile::Unknown, Tile::Unknown]];
+    let expected = vec![vec![Tile::Mine, Tile::Count(1), Tile::Count(0)]];
+    assert_eq!(annotate_generic(&grid), expected);
+}
+
+#[test]
+fn annotate_with_orthogonal_offsets() {
+    #[rustfmt::skip]
+    let (input, expected) = (&[
+        "   ",
+        " * ",
+        "   ",
+    ], &[
+        " 1 ",
+        "1*1",
+        " 1 ",
+    ]);
+    let actual = annotate_with_offsets(input, &[(-1, 0), (0, -1), (0, 1), (1, 0)]);
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn annotate_with_knight_offsets() {
+    #[rustfmt::skip]
+    let (input, expected) = (&[
+        "*  ",
+        "   ",
+        "   ",
+    ], &[
+        "*  ",
+        "  1",
+        " 1 ",
+    ]);
+    let knight = [
+        (-2, -1),
+        (-2, 1),
+        (-1, -2),
+        (-1, 2),
+        (1, -2),
+        (1, 2),
+        (2, -1),
+        (2, 1),
+    ];
+    let actual = annotate_with_offsets(input, &knight);
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn annotate_with_all_eight_offsets_matches_annotate() {
+    let input = &["* * ", "  * ", "*   "];
+    let eight = [
+        (-1, -1),
+        (-1, 0),
+        (-1, 1),
+        (0, -1),
+        (0, 1),
+        (1, -1),
+        (1, 0),
+        (1, 1),
+    ];
+    assert_eq!(annotate_with_offsets(input, &eight), annotate(input));
+}
+
+#[test]
+fn annotate_hex_even_row_mine() {
+    #[rustfmt::skip]
+    let (input, expected) = (&[
+        "    ",
+        "    ",
+        "  * ",
+        "    ",
+        "    ",
+    ], &[
+        "    ",
+        " 11 ",
+        " 1*1",
+        " 11 ",
+        "    ",
+    ]);
+    let actual = annotate_hex(input);
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn annotate_hex_odd_row_mine() {
+    #[rustfmt::skip]
+    let (input, expected) = (&[
+        "    ",
+        " *  ",
+        "    ",
+    ], &[
+        " 11 ",
+        "1*1 ",
+        " 11 ",
+    ]);
+    let actual = annotate_hex(input);
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn annotate_hex_surrounded_cell_counts_six() {
+    #[rustfmt::skip]
+    let (input, expected) = (&[
+        " **",
+        "* *",
+        " **",
+    ], &[
+        "2**",
+        "*6*",
+        "2**",
+    ]);
+    let actual = annotate_hex(input);
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn annotate_3d_single_layer_matches_annotate() {
+    let layer = vec![" *  * ", "  *   ", "    * "];
+    let actual = annotate_3d(std::slice::from_ref(&layer));
+    assert_eq!(actual, vec![annotate(&layer)]);
+}
+
+#[test]
+fn annotate_3d_mine_reaches_adjacent_layers() {
+    let layers = vec![vec!["   ", "   "], vec![" * ", "   "], vec!["   ", "   "]];
+    let expected = vec![vec!["111", "111"], vec!["1*1", "111"], vec!["111", "111"]];
+    assert_eq!(annotate_3d(&layers), expected);
+}
+
+#[test]
+fn annotate_3d_center_of_full_cube_counts_26() {
+    let full = vec!["***", "***", "***"];
+    let layers = vec![full.clone(), vec!["***", "* *", "***"], full];
+    let actual = annotate_3d(&layers);
+    assert_eq!(actual[1][1], "*Q*");
+}
+
+#[test]
+#[should_panic(expected = "layer 1")]
+fn annotate_3d_rejects_mismatched_layers() {
+    annotate_3d(&[vec!["  ", "  "], vec!["  "]]);
+}
+
+#[test]
+fn reveal_floods_zero_region_and_its_border() {
+    #[rustfmt::skip]
+    let annotated = &[
+        "  1*",
+        "  11",
+        "    ",
+    ];
+    let expected: Vec<(usize, usize)> = (0..3)
+        .flat_map(|row| (0..4).map(move |col| (row, col)))
+        .filter(|&cell| cell != (0, 3))
+        .collect();
+    assert_eq!(reveal(annotated, (2, 0)), expected);
+}
+
+#[test]
+fn reveal_numbered_cell_reveals_only_itself() {
+    let annotated = &["1*1", "111"];
+    assert_eq!(reveal(annotated, (1, 1)), vec![(1, 1)]);
+}
+
+#[test]
+fn reveal_stops_at_numbers() {
+    #[rustfmt::skip]
+    let annotated = &[
+        " 1*1 ",
+        " 111 ",
+    ];
+    assert_eq!(
+        reveal(annotated, (0, 0)),
+        vec![(0, 0), (0, 1), (1, 0), (1, 1)]
+    );
+}
+
+#[test]
+fn reveal_mine_or_out_of_range_reveals_nothing() {
+    let annotated = &["1*1"];
+    assert!(reveal(annotated, (0, 1)).is_empty());
+    assert!(reveal(annotated, (5, 0)).is_empty());
+}
+
+#[test]
+fn mine_positions_in_row_major_order() {
+    let input = &[" * ", "*  ", "  *"];
+    assert_eq!(mine_positions(input), vec![(0, 1), (1, 0), (2, 2)]);
+}
+
+#[test]
+fn mine_positions_empty_and_mine_free() {
+    assert!(mine_positions(&[]).is_empty());
+    assert!(mine_positions(&["   ", "   "]).is_empty());
+}
+
+#[test]
+fn count_mines_totals_all_rows() {
+    assert_eq!(count_mines(&[" * * ", "*****", "     "]), 7);
+    assert_eq!(count_mines(&[]), 0);
+    let field = generate(10, 10, 23, 5);
+    let rows: Vec<&str> = field.iter().map(String::as_str).collect();
+    assert_eq!(count_mines(&rows), 23);
+}
+
+#[test]
+fn packed_round_trip_is_lossless() {
+    #[rustfmt::skip]
+    let annotated = &[
+        "1*22*1",
+        "12*322",
+        " 123*2",
+        "112*4*",
+        "1*22*2",
+        "111111",
+    ];
+    let packed = to_packed(annotated);
+    assert_eq!(packed.len(), 8 + 18);
+    assert_eq!(from_packed(&packed).unwrap(), annotated);
+}
+
+#[test]
+fn packed_layout_and_odd_cell_count() {
+    let packed = to_packed(&["*8 "]);
+    assert_eq!(packed, vec![1, 0, 0, 0, 3, 0, 0, 0, 0x8F, 0x00]);
+    assert_eq!(from_packed(&packed).unwrap(), vec!["*8 "]);
+}
+
+#[test]
+fn packed_empty_board() {
+    assert_eq!(from_packed(&to_packed(&[])).unwrap(), Vec::<String>::new());
+}
+
+#[test]
+#[should_panic(expected = "invalid minefield: row 1")]
+fn to_packed_validates_rows_before_allocating() {
+    wide_then_ragged(to_packed);
+}
+
+#[test]
+fn from_packed_reports_errors() {
+    assert_eq!(
+        from_packed(&[1, 0]),
+        Err(DecodeError::MissingHeader { found: 2 })
+    );
+    assert_eq!(
+        from_packed(&[1, 0, 0, 0, 2, 0, 0, 0]),
+        Err(DecodeError::LengthMismatch {
+            expected: 9,
+            found: 8,
+        })
+    );
+    assert_eq!(
+        from_packed(&[1, 0, 0, 0, 2, 0, 0, 0, 0x9F]),
+        Err(DecodeError::InvalidNibble { cell: 1, value: 9 })
+    );
+}
+
+#[test]
+fn from_packed_rejects_headers_whose_cell_count_overflows() {
+    // 65536 x 65536 is 2^32 cells: one past usize::MAX on 32-bit targets,
+    // where it must not wrap to 0 and slip past the length check
+    let header = [0, 0, 1, 0, 0, 0, 1, 0];
+    let expected = if usize::BITS <= 32 {
+        DecodeError::TooLarge {
+            rows: 65536,
+            cols: 65536,
+        }
+    } else {
+        DecodeError::LengthMismatch {
+            expected: 8 + (1 << 31),
+            found: 8,
+        }
+    };
+    assert_eq!(from_packed(&header), Err(expected));
+
+    let too_large = DecodeError::TooLarge {
+        rows: 65536,
+        cols: 65536,
+    };
+    assert_eq!(
+        too_large.to_string(),
+        "packed board declares 65536x65536 cells, more than usize can index"
+    );
+}
+
+#[test]
+fn annotate_view_indexes_cells_and_rows() {
+    let input = &[" * ", "   "];
+    let view = annotate_view(input);
+    assert_eq!((view.rows(), view.cols()), (2, 3));
+    assert_eq!(view.cell(0, 0), b'1');
+    assert_eq!(view.cell(0, 1), b'*');
+    assert_eq!(view.row_str(1), "111");
+    assert_eq!(view.into_strings(), annotate(input));
+}
+
+#[test]
+fn annotate_view_empty() {
+    let view = annotate_view(&[]);
+    assert_eq!((view.rows(), view.cols()), (0, 0));
+    assert!(view.into_strings().is_empty());
+}
+
+#[test]
+fn stream_annotator_matches_annotate() {
+    #[rustfmt::skip]
+    let input = [
+        " *  * ",
+        "  *   ",
+        "    * ",
+        "   * *",
+        " *  * ",
+        "      ",
+    ];
+    let mut stream = StreamAnnotator::new();
+    let mut actual: Vec<String> = input
+        .iter()
+        .filter_map(|row| stream.push_row(row))
+        .collect();
+    actual.extend(stream.finish());
+    assert_eq!(actual, annotate(&input));
+}
+
+#[test]
+fn stream_annotator_single_and_no_rows() {
+    let mut stream = StreamAnnotator::new();
+    assert_eq!(stream.push_row("* *"), None);
+    assert_eq!(stream.finish(), vec!["*2*"]);
+    assert!(StreamAnnotator::new().finish().is_empty());
+}
+
+#[test]
+#[should_panic(expected = "invalid minefield")]
+fn stream_annotator_rejects_ragged_rows() {
+    let mut stream = StreamAnnotator::new();
+    stream.push_row("   ");
+    stream.push_row("  ");
+}
+
+#[test]
+fn render_ansi_plain_text() {
+    assert_eq!(render_ansi(&["1*1", "111"], false), "1*1\n111\n");
+    assert_eq!(render_ansi(&[], false), "");
+}
+
+#[test]
+fn render_ansi_colors_each_cell_and_resets_lines() {
+    let expected = "\x1b[0;94m1\x1b[0;1;31m*\x1b[0;32m2\x1b[0;100m \x1b[0m\n";
+    assert_eq!(render_ansi(&["1*2 "], true), expected);
+}
+
+#[test]
+fn transforms_on_a_small_board() {
+    let input = &["ab", "cd", "ef"];
+    assert_eq!(transpose(input), vec!["ace", "bdf"]);
+    assert_eq!(rotate_cw(input), vec!["eca", "fdb"]);
+    assert_eq!(rotate_ccw(input), vec!["bdf", "ace"]);
+    assert_eq!(flip_horizontal(input), vec!["ba", "dc", "fe"]);
+    assert!(transpose(&[]).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "invalid minefield")]
+fn transforms_reject_ragged_boards() {
+    rotate_cw(&["  ", " "]);
+}
+
+#[test]
+fn annotate_is_equivariant_under_transforms() {
+    fn as_rows(board: &[String]) -> Vec<&str> {
+        board.iter().map(String::as_str).collect()
+    }
+
+    type Transform = fn(&[&str]) -> Vec<String>;
+    let transforms: [Transform; 4] = [transpose, rotate_cw, rotate_ccw, flip_horizontal];
+    for seed in 0..50 {
+        let rows = 1 + (seed as usize % 7);
+        let cols = 1 + (seed as usize * 3 % 9);
+        let field = generate(rows, cols, rows * cols / 3, seed);
+        let field = as_rows(&field);
+        let annotated = annotate(&field);
+        for transform in transforms {
+            let transformed = transform(&field);
+            assert_eq!(
+                annotate(&as_rows(&transformed)),
+                transform(&as_rows(&annotated)),
+                "seed {seed}"
+            );
+        }
+    }
+}
+
+#[test]
+fn annotator_defaults_match_annotate() {
+    let annotator = AnnotatorConfig::new().build();
+    #[rustfmt::skip]
+    let input = &[
+        " *  * ",
+        "  *   ",
+        "    * ",
+        "   * *",
+    ];
+    assert_eq!(annotator.annotate(input), annotate(input));
+}
+
+#[test]
+fn annotator_wrap_matches_annotate_toroidal() {
+    let annotator = AnnotatorConfig::new().wrap(true).build();
+    for input in [
+        &["*"][..],
+        &["* "],
+        &["*  ", "   ", "   "],
+        &["*   ", "    ", "   *"],
+    ] {
+        assert_eq!(annotator.annotate(input), annotate_toroidal(input));
+    }
+}
+
+#[test]
+fn annotator_custom_mine_and_offsets() {
+    let annotator = AnnotatorConfig::new()
+        .mine(b'#')
+        .offsets(&OFFSETS_4)
+        .build();
+    let input = &["   ", " # ", "   "];
+    assert_eq!(annotator.annotate(input), vec![" 1 ", "1#1", " 1 "]);
+}
+
+#[test]
+fn annotator_wide_counts_beyond_nine() {
+    let radius_2: Vec<(isize, isize)> = (-2..=2)
+        .flat_map(|row| (-2..=2).map(move |col| (row, col)))
+        .filter(|&offset| offset != (0, 0))
+        .collect();
+    let annotator = AnnotatorConfig::new().offsets(&radius_2).wide(true).build();
+    let input = &["*****", "*****", "** **", "*****", "*****"];
+    assert_eq!(annotator.annotate(input)[2], "**O**");
+}
+
+#[test]
+#[should_panic(expected = "collides with empty cells or counts")]
+fn annotator_wide_rejects_letter_marker() {
+    AnnotatorConfig::new().mine(b'A').wide(true).build();
+}
+
+#[test]
+fn annotator_narrow_accepts_letter_marker() {
+    let annotator = AnnotatorConfig::new()
+        .mine(b'A')
+        .offsets(&OFFSETS_4)
+        .build();
+    assert_eq!(annotator.annotate(&["A ", "  "]), vec!["A1", "1 "]);
+}
+
+#[test]
+#[should_panic(expected = "invalid minefield: row 1")]
+fn annotator_validates_rows_before_allocating() {
+    let annotator = AnnotatorConfig::new().offsets(&OFFSETS_4).build();
+    wide_then_ragged(|rows| annotator.annotate(rows));
+}
+
+#[test]
+#[should_panic(expected = "enable wide counts")]
+fn annotator_narrow_overflow_panics() {
+    let offsets: Vec<(isize, isize)> = (1..=10).map(|col| (0, col)).collect();
+    let annotator = AnnotatorConfig::new().offsets(&offsets).build();
+    annotator.annotate(&["********** "]);
+}
+
+#[test]
+fn verify_accepts_annotate_output() {
+    for seed in 0..20 {
+        let field = generate(6, 9, 15, seed);
+        let raw: Vec<&str> = field.iter().map(String::as_str).collect();
+        let annotated = annotate(&raw);
+        let annotated: Vec<&str> = annotated.iter().map(String::as_str).collect();
+        assert!(verify(&raw, &annotated), "seed {seed}");
+    }
+}
+
+#[test]
+fn find_mismatch_reports_first_bad_cell() {
+    let raw = &[" * ", "   "];
+    assert_eq!(find_mismatch(raw, &["1*1", "111"]), None);
+    assert_eq!(find_mismatch(raw, &["1*1", "121"]), Some((1, 1)));
+    assert_eq!(find_mismatch(raw, &["1*", "111"]), Some((0, 2)));
+    assert_eq!(find_mismatch(raw, &["1*1"]), Some((1, 0)));
+    assert!(!verify(raw, &["   ", "   "]));
+}
+
+#[test]
+fn zero_width_rows_annotate_to_empty_rows() {
+    assert_eq!(annotate(&["", ""]), vec!["".to_string(), "".to_string()]);
+    assert_eq!(annotate(&[""]), vec!["".to_string()]);
+    assert_eq!(try_annotate(&["", "", ""]), Ok(vec![String::new(); 3]));
+}
+
+#[test]
+fn empty_row_mixed_with_non_empty_is_ragged() {
+    assert_eq!(
+        try_annotate(&["", "*"]),
+        Err(AnnotateError::RaggedRows {
+            row: 1,
+            expected: 0,
+            found: 1
+        })
+    );
+    assert_eq!(
+        try_annotate(&["*", ""]),
+        Err(AnnotateError::RaggedRows {
+            row: 1,
+            expected: 1,
+            found: 0
+        })
+    );
+}
+
+#[test]
+#[should_panic(expected = "invalid minefield")]
+fn annotate_panics_on_empty_row_mixed_with_non_empty() {
+    annotate(&["", "*"]);
+}
+
+#[test]
+fn annotate_iter_matches_annotate() {
+    let minefield = ["  *  ", "  *  ", "*****", "  *  ", "  *  "];
+    let rows: Vec<String> = annotate_iter(&minefield).collect();
+    assert_eq!(rows, annotate(&minefield));
+    assert_eq!(annotate_iter(&[]).count(), 0);
+}
+
+#[test]
+fn annotate_iter_yields_rows_lazily_in_order() {
+    let mut rows = annotate_iter(&["* ", "  ", " *"]);
+    assert_eq!(rows.next().as_deref(), Some("*1"));
+    assert_eq!(rows.next().as_deref(), Some("22"));
+    assert_eq!(rows.next().as_deref(), Some("1*"));
+    assert_eq!(rows.next(), None);
+}
+
+#[test]
+#[should_panic(expected = "invalid minefield")]
+fn annotate_iter_panics_before_yielding_on_invalid_input() {
+    let _ = annotate_iter(&["  ", " "]);
+}
+
+#[test]
+fn field_stats_counts_cells_mines_and_adjacency() {
+    let stats = field_stats(&["*  ", "   ", "  *"]);
+    // annotated: "*1 ", "121", " 1*"
+    assert_eq!(stats.total_cells, 9);
+    assert_eq!(stats.mines, 2);
+    assert!((stats.density - 2.0 / 9.0).abs() < 1e-12);
+    assert_eq!(stats.adjacency, [2, 4, 1, 0, 0, 0, 0, 0, 0]);
+}
+
+#[test]
+fn field_stats_of_empty_board_has_zero_density() {
+    let stats = field_stats(&[]);
+    assert_eq!(stats.total_cells, 0);
+    assert_eq!(stats.density, 0.0);
+    assert_eq!(stats.adjacency, [0; 9]);
+}
+
+#[test]
+fn field_stats_display_summarizes() {
+    assert_eq!(
+        field_stats(&["* ", "  "]).to_string(),
+        "1 mines in 4 cells (25.0% density); counts 0-8: [0, 3, 0, 0, 0, 0, 0, 0, 0]"
+    );
+}
+
+#[test]
+fn mine_clusters_joins_diagonal_and_orthogonal_neighbors() {
+    let minefield = ["**   ", "  *  ", "     ", "    *", "*   *"];
+    assert_eq!(
+        mine_clusters(&minefield),
+        vec![
+            vec![(0, 0), (0, 1), (1, 2)],
+            vec![(3, 4), (4, 4)],
+            vec![(4, 0)],
+        ]
+    );
+}
+
+#[test]
+fn mine_clusters_of_board_without_mines_is_empty() {
+    assert!(mine_clusters(&["   ", "   "]).is_empty());
+    assert!(mine_clusters(&[]).is_empty());
+}
+
+#[test]
+fn mine_clusters_of_full_board_is_one_cluster() {
+    let clusters = mine_clusters(&["***", "***"]);
+    assert_eq!(clusters.len(), 1);
+    assert_eq!(clusters[0].len(), 6);
+}
+
+#[test]
+fn deduce_single_cell_rules() {
+    // A '1' with three unknown neighbors decides nothing on its own
+    let deductions = deduce(&["1?", "??"]);
+    assert!(deductions.safe.is_empty());
+    assert!(deductions.mines.is_empty());
+
+    // Only one unknown neighbor is left to hold the mine
+    let deductions = deduce(&["1?", "11"]);
+    assert_eq!(deductions.mines, [(0, 1)].into_iter().collect());
+    assert!(deductions.safe.is_empty());
+
+    // A revealed zero clears all of its unknown neighbors
+    let deductions = deduce(&["  ", "??"]);
+    assert_eq!(deductions.safe, [(1, 0), (1, 1)].into_iter().collect());
+    assert!(deductions.mines.is_empty());
+}
+
+#[test]
+fn deduce_counts_flagged_mines() {
+    // The '1' already touches a flagged mine, so its other unknown is safe.
+    let deductions = deduce(&["*1?"]);
+    assert_eq!(deductions.safe, [(0, 2)].into_iter().collect());
+}
+
+#[test]
+fn deduce_subset_rule() {
+    // No single '1' decides anything. The middle one sees {(0,0),(0,1),(0,2)}
+    // and each side one sees a two-cell subset of that with the same single
+    // mine, so (0,2) and (0,0) are safe; that leaves (0,1) as the mine.
+    let deductions = deduce(&["???", "111"]);
+    assert_eq!(deductions.mines, [(0, 1)].into_iter().collect());
+    assert_eq!(deductions.safe, [(0, 0), (0, 2)].into_iter().collect());
+}
+
+#[test]
+fn deduce_returns_empty_sets_when_nothing_follows() {
+    assert_eq!(deduce(&["??", "??"]), Deductions::default());
+    assert_eq!(deduce(&[]), Deductions::default());
+}
+
+#[test]
+#[should_panic(expected = "invalid board byte")]
+fn deduce_panics_on_unknown_byte() {
+    deduce(&["1x"]);
+}
+
+#[test]
+fn grid_parse_and_get() {
+    let grid = Grid::parse(&["* ", "  ", " *"]).unwrap();
+    assert_eq!((grid.rows(), grid.cols()), (3, 2));
+    assert_eq!(grid.get(0, 0), Some(b'*'));
+    assert_eq!(grid.get(2, 0), Some(b' '));
+    assert_eq!(grid.get(2, 1), Some(b'*'));
+    assert_eq!(grid.get(3, 0), None);
+    assert_eq!(grid.get(0, 2), None);
+}
+
+#[test]
+fn grid_annotate_matches_annotate() {
+    let minefield = [" *  * ", "  *   ", "    * ", "   * *", " *  * ", "      "];
+    let grid = Grid::parse(&minefield).unwrap();
+    assert_eq!(grid.annotate().to_strings(), annotate(&minefield));
+    // The source grid is left unannotated
+    assert_eq!(grid.to_strings(), minefield);
+}
+
+#[test]
+fn grid_round_trips_degenerate_shapes() {
+    for minefield in [&[][..], &["", ""][..], &["*"][..], &[" * "][..]] {
+        let grid = Grid::parse(minefield).unwrap();
+        assert_eq!(grid.to_strings(), minefield);
+        assert_eq!(grid.annotate().to_strings(), annotate(minefield));
+    }
+}
+
+#[test]
+fn grid_parse_rejects_ragged_rows() {
+    assert_eq!(
+        Grid::parse(&["  ", " "]),
+        Err(AnnotateError::RaggedRows {
+            row: 1,
+            expected: 2,
+            found: 1
+        })
+    );
+}
+
+/// Width of the first row in [`wide_then_ragged`].
+const WIDE_COLS: usize = 1 << 25;
+
+/// Calls `f` on a board whose wide first row is followed by `1 << 22` empty,
+/// ragged rows. Sizing a buffer from the first row before checking the rest
+/// would ask for 128 TiB, more than the address space, so only code that
+/// validates every row first reaches the `RaggedRows` error.
+fn wide_then_ragged<T>(f: impl FnOnce(&[&str]) -> T) -> T {
+    let wide = " ".repeat(WIDE_COLS);
+    let mut rows = vec![""; 1 << 22];
+    rows[0] = &wide;
+    f(&rows)
+}
+
+#[test]
+fn grid_parse_validates_rows_before_allocating() {
+    assert_eq!(
+        wide_then_ragged(Grid::parse),
+        Err(AnnotateError::RaggedRows {
+            row: 1,
+            expected: WIDE_COLS,
+            found: 0
+        })
+    );
+}
+
+#[test]
+#[should_panic(expected = "invalid minefield: row 1")]
+fn annotate_flat_validates_rows_before_allocating() {
+    wide_then_ragged(annotate_flat);
+}
+
+#[test]
+fn annotate_flat_matches_annotate() {
+    for seed in 0..20 {
+        let field = generate(13, 7, (seed as usize * 4) % 91, seed);
+        let rows: Vec<&str> = field.iter().map(String::as_str).collect();
+        assert_eq!(annotate_flat(&rows), annotate(&rows), "seed {seed}");
+    }
+    assert_eq!(annotate_flat(&[]), Vec::<String>::new());
+    assert_eq!(annotate_flat(&["", ""]), vec![String::new(), String::new()]);
+}
+
+#[test]
+#[should_panic(expected = "invalid minefield")]
+fn annotate_flat_panics_on_invalid_input() {
+    annotate_flat(&["*x"]);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "cannot increment cell '9'")]
+fn increment_of_nine_panics_in_debug_builds() {
+    let mut grid = vec![b"*9".to_vec()];
+    annotate_in_place(&mut grid);
+}
+
+#[test]
+#[cfg(not(debug_assertions))]
+fn increment_of_nine_is_left_unchanged_in_release_builds() {
+    let mut grid = vec![b"*9".to_vec()];
+    annotate_in_place(&mut grid);
+    assert_eq!(grid, vec![b"*9".to_vec()]);
+}
+
+#[test]
+fn increment_of_eight_neighbors_reaches_eight() {
+    let mut grid = vec![b"***".to_vec(), b"* *".to_vec(), b"***".to_vec()];
+    annotate_in_place(&mut grid);
+    assert_eq!(grid[1], b"*8*".to_vec());
+}
+
+#[test]
+fn annotate_with_empty_renders_zero_cells() {
+    assert_eq!(
+        annotate_with_empty(&["*   ", "    "], b'.'),
+        vec!["*1..", "11.."]
+    );
+    // A space output is the same as annotate
+    let minefield = [" * ", "   ", "*  "];
+    assert_eq!(annotate_with_empty(&minefield, b' '), annotate(&minefield));
+}
+
+#[test]
+#[should_panic(expected = "invalid minefield")]
+fn annotate_with_empty_still_rejects_dots_in_input() {
+    annotate_with_empty(&[". *"], b'.');
+}
+
+#[test]
+#[should_panic(expected = "collides with mines or counts")]
+fn annotate_with_empty_rejects_digit_marker() {
+    annotate_with_empty(&[" *"], b'0');
+}
+
+#[cfg(feature = "wasm")]
+#[test]
+fn annotate_js_round_trips_newline_joined_boards() {
+    assert_eq!(annotate_js("* \n  ").unwrap(), "*1\n11");
+    assert_eq!(annotate_js("* \n  \n").unwrap(), "*1\n11\n");
+    assert_eq!(annotate_js("* \r\n  \r\n").unwrap(), "*1\n11\n");
+    assert_eq!(annotate_js("").unwrap(), "");
+    assert_eq!(annotate_js("\n").unwrap(), "\n");
+}
+
+#[test]
+fn annotate_str_preserves_trailing_newline() {
+    assert_eq!(annotate_str(" * \n   "), "1*1\n111");
+    assert_eq!(annotate_str(" * \n   \n"), "1*1\n111\n");
+}
+
+#[test]
+fn annotate_str_accepts_crlf_on_every_line() {
+    assert_eq!(annotate_str(" * \r\n   "), "1*1\n111");
+    assert_eq!(annotate_str(" * \r\n   \r\n"), "1*1\n111\n");
+    assert_eq!(annotate_str(" * \n   \r\n"), "1*1\n111\n");
+}
+
+#[test]
+fn annotate_str_handles_empty_boards() {
+    assert_eq!(annotate_str(""), "");
+    assert_eq!(annotate_str("\n"), "\n");
+    assert_eq!(annotate_str("\r\n"), "\n");
+    // Only one trailing newline is a terminator; the one before it ends an empty row
+    assert_eq!(annotate_str("\n\n"), "\n\n");
+}
+
+#[test]
+fn try_annotate_str_strips_exactly_one_terminator() {
+    // The second newline ends an empty row, which is ragged against "*"
+    assert_eq!(
+        try_annotate_str("*\n\n"),
+        Err(AnnotateError::RaggedRows {
+            row: 1,
+            expected: 1,
+            found: 0,
+        })
+    );
+    assert_eq!(
+        try_annotate_str("**\n*\n"),
+        Err(AnnotateError::RaggedRows {
+            row: 1,
+            expected: 2,
+            found: 1,
+        })
+    );
+}
+
+#[test]
+fn try_annotate_str_rejects_bare_carriage_returns() {
+    assert_eq!(
+        try_annotate_str("* \r  \n"),
+        Err(AnnotateError::InvalidChar {
+            row: 0,
+            col: 2,
+            byte: b'\r',
+        })
+    );
+}
+
+#[test]
+#[should_panic(expected = "invalid minefield")]
+fn annotate_str_rejects_ragged_lines() {
+    annotate_str("**\n*\n");
+}
+
+#[test]
+fn annotate_into_matches_annotate_and_resizes() {
+    let mut out = vec!["stale".to_string(); 5];
+    annotate_into(&["* ", "  "], &mut out);
+    assert_eq!(out, annotate(&["* ", "  "]));
+
+    annotate_into(&[" * ", "   ", "*  "], &mut out);
+    assert_eq!(out, vec!["1*1", "221", "*1 "]);
+
+    annotate_into(&[], &mut out);
+    assert!(out.is_empty());
+}
+
+#[test]
+fn annotate_into_reuses_row_buffers() {
+    let mut out = Vec::new();
+    annotate_into(&["*   ", "    ", "   *"], &mut out);
+    let row_ptrs: Vec<*const u8> = out.iter().map(|row| row.as_ptr()).collect();
+
+    annotate_into(&["  * ", "*   ", "    "], &mut out);
+    assert_eq!(out, annotate(&["  * ", "*   ", "    "]));
+    let reused: Vec<*const u8> = out.iter().map(|row| row.as_ptr()).collect();
+    assert_eq!(reused, row_ptrs);
+}
+
+#[test]
+fn annotate_into_leaves_buffer_untouched_on_invalid_input() {
+    let mut out = vec!["kept".to_string()];
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        annotate_into(&["* ", "x "], &mut out)
+    }));
+    assert!(result.is_err());
+    assert_eq!(out, vec!["kept"]);
+}
+
+#[test]
+fn counts_returns_numbers_and_mine_sentinel() {
+    let m = MINE_SENTINEL;
+    assert_eq!(
+        counts(&["*  ", " * ", "   "]),
+        vec![vec![m, 2, 1], vec![2, m, 1], vec![1, 1, 1]]
+    );
+    assert_eq!(counts(&["***", "* *", "***"])[1][1], 8);
+    assert!(counts(&[]).is_empty());
+}
+
+#[test]
+fn counts_agree_with_annotate() {
+    let field = generate(9, 11, 30, 7);
+    let rows: Vec<&str> = field.iter().map(String::as_str).collect();
+    for (count_row, annotated_row) in counts(&rows).iter().zip(annotate(&rows)) {
+        for (&count, cell) in count_row.iter().zip(annotated_row.bytes()) {
+            let expected = match cell {
+                b'*' => MINE_SENTINEL,
+                b' ' => 0,
+                digit => digit - b'0',
+            };
+            assert_eq!(count, expected);
+        }
+    }
+}
+
+#[test]
+fn diff_lists_differing_cells() {
+    assert_eq!(
+        diff(&["*1 ", " 1*"], &["*2 ", " 1 "]),
+        Ok(vec![(0, 1, b'1', b'2'), (1, 2, b'*', b' ')])
+    );
+    assert_eq!(diff(&["* ", " *"], &["* ", " *"]), Ok(vec![]));
+    assert_eq!(diff(&[], &[]), Ok(vec![]));
+}
+
+#[test]
+fn diff_rejects_mismatched_dimensions() {
+    assert_eq!(
+        diff(&["  ", "  "], &["  "]),
+        Err(DiffError::RowCount { a: 2, b: 1 })
+    );
+    assert_eq!(
+        diff(&["  ", "  "], &["  ", "   "]),
+        Err(DiffError::RowLength { row: 1, a: 2, b: 3 })
+    );
+}
+
+#[test]
+fn annotate_radius_one_matches_annotate() {
+    for seed in 0..10 {
+        let field = generate(8, 13, 20 + seed as usize, seed);
+        let rows: Vec<&str> = field.iter().map(String::as_str).collect();
+        assert_eq!(annotate_radius(&rows, 1), annotate(&rows), "seed {seed}");
+    }
+}
+
+#[test]
+fn annotate_radius_counts_the_whole_window() {
+    // Radius 2 around a single mine reaches a 5x5 square
+    assert_eq!(
+        annotate_radius(
+            &["      ", "      ", "  *   ", "      ", "      ", "      "],
+            2
+        ),
+        vec!["11111 ", "11111 ", "11*11 ", "11111 ", "11111 ", "      "]
+    );
+    assert_eq!(annotate_radius(&["* *", "   "], 0), vec!["* *", "   "]);
+}
+
+#[test]
+fn annotate_radius_uses_wide_counts() {
+    // Every mine of a 5x5 board except the center is within radius 2 of it
+    let rows = ["*****", "*****", "** **", "*****", "*****"];
+    assert_eq!(annotate_radius(&rows, 2)[2], "**O**");
+}
+
+#[test]
+#[should_panic(expected = "exceeds")]
+fn annotate_radius_panics_when_counts_overflow() {
+    let field = [
+        "*******", "*******", "*******", "*** ***", "*******", "*******", "*******",
+    ];
+    annotate_radius(&field, 3);
+}
+
+#[test]
+fn annotate_radius_clamps_huge_radius() {
+    assert_eq!(annotate_radius(&["* ", "  "], usize::MAX), vec!["*1", "11"]);
+}
+
+#[test]
+fn neighbor_offsets_drive_the_default_neighborhood() {
+    assert_eq!(NEIGHBOR_OFFSETS, OFFSETS_8);
+    assert!(!NEIGHBOR_OFFSETS.contains(&(0, 0)));
+    for seed in 0..10 {
+        let field = generate(7, 9, 15 + seed as usize, seed);
+        let rows: Vec<&str> = field.iter().map(String::as_str).collect();
+        assert_eq!(
+            annotate_with_offsets(&rows, &NEIGHBOR_OFFSETS),
+            annotate(&rows)
+        );
+    }
+}
+
+#[test]
+fn parse_annotated_maps_cells_to_numbers() {
+    let annotated = annotate(&["*  ", "  *"]);
+    let rows: Vec<&str> = annotated.iter().map(String::as_str).collect();
+    assert_eq!(
+        parse_annotated(&rows),
+        Ok(vec![vec![-1, 2, 1], vec![1, 2, -1]])
+    );
+    assert_eq!(parse_annotated(&[]), Ok(vec![]));
+}
+
+#[test]
+fn parse_annotated_reports_invalid_byte_location() {
+    assert_eq!(
+        parse_annotated(&["1*", "*9"]),
+        Err(ParseError {
+            row: 1,
+            col: 1,
+            byte: b'9'
+        })
+    );
+    assert_eq!(
+        parse_annotated(&["0"]).unwrap_err().to_string(),
+        "invalid byte 0x30 at row 0, column 0; expected ' ', '1'-'8' or '*'"
+    );
+}
+
+#[test]
+fn annotate_checked_matches_annotate() {
+    for (rows, cols, seed) in [(1, 1, 0), (1, 17, 1), (17, 1, 2), (12, 12, 3), (30, 7, 4)] {
+        for density in [0, 15, 50, 100] {
+            let field = generate(rows, cols, rows * cols * density / 100, seed);
+            let input: Vec<&str> = field.iter().map(String::as_str).collect();
+            // annotate_with always takes the fast path, even under debug-safe
+            assert_eq!(annotate_checked(&input), annotate_with(&input, b'*'));
+        }
+    }
+    assert!(annotate_checked(&[]).is_empty());
+    assert_eq!(annotate_checked(&["", ""]), vec!["", ""]);
+}
+
+#[test]
+#[should_panic(expected = "invalid minefield")]
+fn annotate_checked_panics_on_invalid_input() {
+    annotate_checked(&[" ", "  "]);
+}
+
+#[cfg(feature = "debug-safe")]
+#[test]
+fn annotate_dispatches_to_checked_path_under_debug_safe() {
+    assert_eq!(annotate(SNAPSHOT_RAW), SNAPSHOT_ANNOTATED);
+    for (rows, cols, seed) in [(1, 1, 0), (1, 17, 1), (17, 1, 2), (12, 12, 3), (30, 7, 4)] {
+        let field = generate(rows, cols, rows * cols / 3, seed);
+        let input: Vec<&str> = field.iter().map(String::as_str).collect();
+        assert_eq!(annotate(&input), annotate_checked(&input));
+        assert_eq!(annotate(&input), annotate_with(&input, b'*'));
+    }
+    assert!(annotate(&[]).is_empty());
+    assert_eq!(annotate(&["", ""]), vec!["", ""]);
+}
+
+#[cfg(feature = "debug-safe")]
+#[test]
+#[should_panic(expected = "invalid minefield")]
+fn annotate_under_debug_safe_panics_on_invalid_input() {
+    annotate(&[" ", "  "]);
+}
+
+#[test]
+fn neighbors_yields_in_bounds_cells_in_offset_order() {
+    let all: Vec<_> = neighbors(1, 1, 3, 3).collect();
+    assert_eq!(
+        all,
+        vec![
+            (0, 0),
+            (0, 1),
+            (0, 2),
+            (1, 0),
+            (1, 2),
+            (2, 0),
+            (2, 1),
+            (2, 2)
+        ]
+    );
+    assert_eq!(
+        neighbors(0, 0, 3, 3).collect::<Vec<_>>(),
+        vec![(0, 1), (1, 0), (1, 1)]
+    );
+    assert_eq!(
+        neighbors(2, 2, 3, 3).collect::<Vec<_>>(),
+        vec![(1, 1), (1, 2), (2, 1)]
+    );
+}
+
+#[test]
+fn neighbors_handles_degenerate_boards() {
+    assert_eq!(neighbors(0, 0, 1, 1).count(), 0);
+    assert_eq!(
+        neighbors(0, 2, 1, 5).collect::<Vec<_>>(),
+        vec![(0, 1), (0, 3)]
+    );
+    assert_eq!(
+        neighbors(2, 0, 5, 1).collect::<Vec<_>>(),
+        vec![(1, 0), (3, 0)]
+    );
+    let last = usize::MAX - 1;
+    assert_eq!(
+        neighbors(last, last, usize::MAX, usize::MAX).collect::<Vec<_>>(),
+        vec![(last - 1, last - 1), (last - 1, last), (last, last - 1)]
+    );
+    assert_eq!(
+        neighbors(usize::MAX, usize::MAX, usize::MAX, usize::MAX).count(),
+        1
+    );
+}
+
+#[test]
+fn count_adjacent_mines_agrees_with_neighbors() {
+    let field = generate(9, 9, 30, 11);
+    let rows: Vec<&str> = field.iter().map(String::as_str).collect();
+    for row in 0..9 {
+        for col in 0..9 {
+            let expected = neighbors(row, col, 9, 9)
+                .filter(|&(r, c)| rows[r].as_bytes()[c] == b'*')
+                .count();
+            assert_eq!(count_adjacent_mines(&rows, row, col) as usize, expected);
+        }
+    }
+}
+
+#[test]
+fn partial_leaves_unknown_cells_untouched() {
+    assert_eq!(
+        annotate_partial(&["?* ", "?  ", "???"]),
+        vec!["?*1", "?11", "???"]
+    );
+}
+
+#[test]
+fn partial_without_unknown_cells_matches_annotate() {
+    let field = generate(8, 11, 20, 5);
+    let rows: Vec<&str> = field.iter().map(String::as_str).collect();
+    assert_eq!(annotate_partial(&rows), annotate(&rows));
+}
+
+#[test]
+fn partial_unknown_cells_never_count_as_mines() {
+    assert_eq!(
+        annotate_partial(&["???", "? ?", "???"]),
+        vec!["???", "? ?", "???"]
+    );
+    assert_eq!(annotate_partial(&[]), Vec::<String>::new());
+}
+
+#[test]
+#[should_panic(expected = "invalid minefield")]
+fn partial_rejects_other_bytes() {
+    annotate_partial(&["?x"]);
+}
+
+#[test]
+#[should_panic(expected = "invalid minefield")]
+fn partial_rejects_ragged_rows() {
+    annotate_partial(&["??", "?"]);
+}
+
+#[test]
+fn sized_reports_dimensions_with_the_annotation() {
+    let input = &[" *  ", "    "];
+    assert_eq!(annotate_sized(input), (2, 4, annotate(input)));
+    assert_eq!(
+        annotate_sized(&["", ""]),
+        (2, 0, vec![String::new(), String::new()])
+    );
+    assert_eq!(annotate_sized(&[]), (0, 0, Vec::new()));
+}
+
+#[test]
+#[should_panic(expected = "invalid minefield")]
+fn sized_rejects_ragged_rows() {
+    annotate_sized(&["  ", " "]);
+}
+
+#[test]
+fn weighted_mixes_two_mine_types() {
+    #[rustfmt::skip]
+    let (input, expected) = (&[
+        "*  ",
+        "  #",
+        "   ",
+    ], &[
+        "*32",
+        "13#",
+        " 22",
+    ]);
+    assert_eq!(annotate_weighted(input, &[(b'*', 1), (b'#', 2)]), expected);
+}
+
+#[test]
+fn weighted_sums_above_nine_use_the_wide_encoding() {
+    #[rustfmt::skip]
+    let (input, expected) = (&[
+        "###",
+        "# #",
+        "###",
+    ], &[
+        "###",
+        "#G#",
+        "###",
+    ]);
+    assert_eq!(annotate_weighted(input, &[(b'#', 2)]), expected);
+}
+
+#[test]
+fn weighted_with_unit_star_matches_annotate() {
+    let field = generate(7, 9, 20, 3);
+    let rows: Vec<&str> = field.iter().map(String::as_str).collect();
+    assert_eq!(annotate_weighted(&rows, &[(b'*', 1)]), annotate(&rows));
+}
+
+#[test]
+#[should_panic(expected = "invalid minefield")]
+fn weighted_rejects_unlisted_mines() {
+    annotate_weighted(&["*#"], &[(b'*', 1)]);
+}
+
+#[test]
+#[should_panic(expected = "listed more than once")]
+fn weighted_rejects_duplicate_markers() {
+    annotate_weighted(&["*"], &[(b'*', 1), (b'*', 2)]);
+}
+
+#[test]
+fn weighted_rejects_letter_and_digit_markers() {
+    for mine in [b'A', b'Z', b'a', b'z', b'0', b'9'] {
+        let payload = std::panic::catch_unwind(|| annotate_weighted(&[" "], &[(mine, 1)]))
+            .expect_err("letter and digit markers must be rejected");
+        let message = payload.downcast_ref::<String>().unwrap();
+        assert!(
+            message.contains("collides with empty cells or counts"),
+            "{message}"
+        );
+    }
+}
+
+#[test]
+#[should_panic(expected = "invalid minefield: row 1")]
+fn weighted_validates_rows_before_allocating() {
+    wide_then_ragged(|rows| annotate_weighted(rows, &[(b'*', 1)]));
+}
+
+#[test]
+fn is_annotated_heuristic_looks_for_count_digits() {
+    assert!(!is_annotated(&[" *  ", "    "]));
+    assert!(is_annotated(&["1*1 ", "111 "]));
+    // No recount: a wrong digit still looks annotated
+    assert!(is_annotated(&["8   "]));
+    // Nothing to count means nothing to detect
+    assert!(!is_annotated(&["   ", "   "]));
+}
+
+#[test]
+fn is_annotated_strict_recounts_every_cell() {
+    let field = generate(6, 8, 12, 21);
+    let rows: Vec<&str> = field.iter().map(String::as_str).collect();
+    let annotated = annotate(&rows);
+    let annotated: Vec<&str> = annotated.iter().map(String::as_str).collect();
+
+    assert!(is_annotated_strict(&annotated));
+    assert!(!is_annotated_strict(&rows));
+    assert!(!is_annotated_strict(&["8   "]));
+    assert!(!is_annotated_strict(&["1* ", "11x"]));
+    assert!(is_annotated_strict(&["   ", "   "]));
+}
+
+#[test]
+fn colmajor_matches_transposing_around_annotate() {
+    let field = generate(5, 9, 14, 8);
+    let rows: Vec<&str> = field.iter().map(String::as_str).collect();
+    let cols = transpose(&rows);
+    let cols: Vec<&str> = cols.iter().map(String::as_str).collect();
+
+    let annotated = annotate(&rows);
+    let annotated: Vec<&str> = annotated.iter().map(String::as_str).collect();
+    assert_eq!(annotate_colmajor(&cols), transpose(&annotated));
+}
+
+#[test]
+fn colmajor_keeps_one_string_per_column() {
+    assert_eq!(
+        annotate_colmajor(&["* ", "  ", "  "]),
+        vec!["*1", "11", "  "]
+    );
+    assert_eq!(annotate_colmajor(&["", ""]), vec!["", ""]);
+}
+
+#[test]
+#[should_panic(expected = "invalid minefield")]
+fn colmajor_rejects_ragged_columns() {
+    annotate_colmajor(&["  ", " "]);
+}
+
+#[test]
+fn mine_error_collects_every_error_type_with_question_mark() {
+    fn pipeline(raw: &[&str], packed: &[u8]) -> Result<u8, MineError> {
+        validate(raw)?;
+        let annotated = try_annotate(raw)?;
+        let annotated: Vec<&str> = annotated.iter().map(String::as_str).collect();
+        parse_annotated(&annotated)?;
+        let decoded = from_packed(packed)?;
+        let decoded: Vec<&str> = decoded.iter().map(String::as_str).collect();
+        diff(&decoded, &annotated)?;
+        Grid::parse(raw)?.try_get(0, 1)
+    }
+
+    let packed = to_packed(&["*1"]);
+    assert_eq!(pipeline(&["* "], &packed), Ok(b' '));
+    assert_eq!(
+        pipeline(&["* ", "*"], &packed),
+        Err(MineError::RaggedRows {
+            row: 1,
+            expected: 2,
+            found: 1,
+        })
+    );
+    assert_eq!(
+        pipeline(&["*x"], &packed),
+        Err(MineError::InvalidChar {
+            row: 0,
+            col: 1,
+            byte: b'x',
+        })
+    );
+    assert_eq!(
+        pipeline(&["* "], &[0; 3]),
+        Err(MineError::Decode(DecodeError::MissingHeader { found: 3 }))
+    );
+    assert_eq!(
+        pipeline(&["* ", "**"], &packed),
+        Err(MineError::Diff(DiffError::RowCount { a: 1, b: 2 }))
+    );
+    assert_eq!(
+        pipeline(&["*"], &to_packed(&["*"])),
+        Err(MineError::OutOfBounds {
+            row: 0,
+            col: 1,
+            rows: 1,
+            cols: 1,
+        })
+    );
+}
+
+#[test]
+fn mine_error_display_and_source() {
+    use std::error::Error;
+
+    let err = MineError::from(ParseError {
+        row: 2,
+        col: 3,
+        byte: b'x',
+    });
+    assert_eq!(err.to_string(), "invalid byte 0x78 at row 2, column 3");
+
+    let decode = DecodeError::MissingHeader { found: 3 };
+    let err = MineError::from(decode.clone());
+    assert_eq!(err.to_string(), format!("invalid packed board: {decode}"));
+    assert_eq!(err.source().unwrap().to_string(), decode.to_string());
+
+    let mismatch = DiffError::RowLength { row: 1, a: 2, b: 3 };
+    let err = MineError::from(mismatch.clone());
+    assert_eq!(
+        err.to_string(),
+        format!("cannot compare boards: {mismatch}")
+    );
+    assert_eq!(err.source().unwrap().to_string(), mismatch.to_string());
+}
+
+/// A board with every count 0–8 and mines on all four corners and edges.
+#[rustfmt::skip]
+const SNAPSHOT_RAW: &[&str] = &[
+    "**** ***",
+    " *  **  ",
+    "***** **",
+    "* **    ",
+    "****   *",
+];
+
+#[rustfmt::skip]
+const SNAPSHOT_ANNOTATED: &[&str] = &[
+    "****4***",
+    "5*76**64",
+    "*****4**",
+    "*8**4233",
+    "****2 1*",
+];
+
+#[test]
+fn snapshot_annotate_every_digit() {
+    assert_eq!(annotate(SNAPSHOT_RAW), SNAPSHOT_ANNOTATED);
+    assert_eq!(annotate_flat(SNAPSHOT_RAW), SNAPSHOT_ANNOTATED);
+    assert_eq!(
+        annotate_str(&SNAPSHOT_RAW.join("\n")),
+        SNAPSHOT_ANNOTATED.join("\n")
+    );
+}
+
+#[test]
+fn snapshot_render_ansi_plain() {
+    assert_eq!(
+        render_ansi(SNAPSHOT_ANNOTATED, false),
+        "****4***\n5*76**64\n*****4**\n*8**4233\n****2 1*\n"
+    );
+}
+
+#[test]
+fn snapshot_render_ansi_color() {
+    let expected = [
+        "\x1b[0;1;31m*\x1b[0;1;31m*\x1b[0;1;31m*\x1b[0;1;31m*\x1b[0;34m4\x1b[0;1;31m*\x1b[0;1;31m*\x1b[0;1;31m*\x1b[0m\n",
+        "\x1b[0;31m5\x1b[0;1;31m*\x1b[0;35m7\x1b[0;36m6\x1b[0;1;31m*\x1b[0;1;31m*\x1b[0;36m6\x1b[0;34m4\x1b[0m\n",
+        "\x1b[0;1;31m*\x1b[0;1;31m*\x1b[0;1;31m*\x1b[0;1;31m*\x1b[0;1;31m*\x1b[0;34m4\x1b[0;1;31m*\x1b[0;1;31m*\x1b[0m\n",
+        "\x1b[0;1;31m*\x1b[0;90m8\x1b[0;1;31m*\x1b[0;1;31m*\x1b[0;34m4\x1b[0;32m2\x1b[0;91m3\x1b[0;91m3\x1b[0m\n",
+        "\x1b[0;1;31m*\x1b[0;1;31m*\x1b[0;1;31m*\x1b[0;1;31m*\x1b[0;32m2\x1b[0;100m \x1b[0;94m1\x1b[0;1;31m*\x1b[0m\n",
+    ]
+    .concat();
+    assert_eq!(render_ansi(SNAPSHOT_ANNOTATED, true), expected);
+}
+
+#[test]
+fn snapshot_field_stats_display() {
+    assert_eq!(
+        field_stats(SNAPSHOT_RAW).to_string(),
+        "25 mines in 40 cells (62.5% density); counts 0-8: [1, 1, 2, 2, 4, 1, 2, 1, 1]"
+    );
+}
+
+#[test]
+fn legend_lists_every_symbol() {
+    assert_eq!(
+        legend(),
+        "*     = mine\n1-8   = adjacent mines\nspace = no adjacent mines\n"
+    );
+}
+
+#[test]
+fn legend_ansi_styles_symbols_like_render_ansi() {
+    let expected = [
+        "\x1b[0;1;31m*\x1b[0m        = mine\n",
+        "\x1b[0;94m1\x1b[0;32m2\x1b[0;91m3\x1b[0;34m4\x1b[0;31m5\x1b[0;36m6\x1b[0;35m7\x1b[0;90m8\x1b[0m = adjacent mines\n",
+        "\x1b[0;100m \x1b[0m        = no adjacent mines\n",
+    ]
+    .concat();
+    assert_eq!(legend_ansi(), expected);
+    assert_eq!(
+        render_ansi(&["12345678"], true).trim_end(),
+        expected
+            .lines()
+            .nth(1)
+            .unwrap()
+            .strip_suffix(" = adjacent mines")
+            .unwrap()
+    );
+}
+
+#[test]
+fn cell_at_reads_annotated_strings_safely() {
+    let board = annotate(&["* ", "  "]);
+    assert_eq!(cell_at(&board, 0, 0), Some(b'*'));
+    assert_eq!(cell_at(&board, 1, 1), Some(b'1'));
+    assert_eq!(cell_at(&board, 0, 2), None);
+    assert_eq!(cell_at(&board, 2, 0), None);
+    assert_eq!(cell_at(&[], 0, 0), None);
+}
+
+#[test]
+fn cell_at_agrees_with_annotate_view() {
+    let field = generate(6, 7, 10, 4);
+    let rows: Vec<&str> = field.iter().map(String::as_str).collect();
+    let board = annotate(&rows);
+    let view = annotate_view(&rows);
+    for row in 0..view.rows() {
+        for col in 0..view.cols() {
+            assert_eq!(cell_at(&board, row, col), Some(view.cell(row, col)));
+        }
+    }
+}
+
+#[test]
+fn balanced_generator_hits_the_target_density() {
+    for (rows, cols, density) in [(20, 20, 0.15), (9, 13, 0.3), (1, 1, 1.0), (5, 5, 0.0)] {
+        let (field, achieved) = generate_balanced(rows, cols, density, 42);
+        assert_eq!(field.len(), rows);
+        assert!(field.iter().all(|row| row.len() == cols));
+
+        let cells = (rows * cols) as f64;
+        let mines: Vec<&str> = field.iter().map(String::as_str).collect();
+        assert_eq!(achieved, count_mines(&mines) as f64 / cells);
+        assert!((achieved - density).abs() <= 0.5 / cells);
+    }
+    assert_eq!(generate_balanced(0, 5, 0.5, 1), (vec![], 0.0));
+}
+
+#[test]
+fn balanced_generator_leaves_no_tile_empty_and_is_reproducible() {
+    let (field, _) = generate_balanced(24, 24, 0.15, 7);
+    assert_eq!(generate_balanced(24, 24, 0.15, 7).0, field);
+    assert_ne!(generate_balanced(24, 24, 0.15, 8).0, field);
+
+    for top in (0..24).step_by(BALANCE_TILE) {
+        for left in (0..24).step_by(BALANCE_TILE) {
+            let tile_mines = field[top..top + BALANCE_TILE]
+                .iter()
+                .map(|row| row[left..left + BALANCE_TILE].matches('*').count())
+                .sum::<usize>();
+            assert!(
+                (1..=4).contains(&tile_mines),
+                "tile ({top}, {left}) has {tile_mines} mines"
+            );
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "not between 0 and 1")]
+fn balanced_generator_rejects_out_of_range_density() {
+    generate_balanced(3, 3, 1.5, 0);
+}
+
+#[test]
+#[should_panic(expected = "more cells than usize can index")]
+fn balanced_generator_rejects_overflowing_dimensions() {
+    generate_balanced(usize::MAX, 2, 0.0, 0);
+}
+
+#[test]
+fn bitset_is_row_major_lsb_first() {
+    let (rows, cols, bits) = to_bitset(&["* ", " *", "**"]);
+    assert_eq!((rows, cols), (3, 2));
+    assert_eq!(bits, vec![0b11_1001]);
+}
+
+#[test]
+fn bitset_round_trips_across_word_boundaries() {
+    for (rows, cols) in [(0, 0), (1, 64), (3, 21), (8, 8), (9, 13), (17, 31)] {
+        let field = generate(rows, cols, rows * cols / 3, 5);
+        let raw: Vec<&str> = field.iter().map(String::as_str).collect();
+        let (rows_out, cols_out, bits) = to_bitset(&raw);
+        assert_eq!(bits.len(), (rows * cols).div_ceil(64));
+        assert_eq!(from_bitset(rows_out, cols_out, &bits), field);
+    }
+}
+
+#[test]
+#[should_panic(expected = "wrong number of bitset words")]
+fn from_bitset_rejects_wrong_word_count() {
+    from_bitset(2, 2, &[0, 0]);
+}
+
+#[test]
+#[should_panic(expected = "past the last cell")]
+fn from_bitset_rejects_stray_high_bits() {
+    from_bitset(2, 2, &[1 << 4]);
+}
+
+#[test]
+#[should_panic(expected = "invalid minefield")]
+fn to_bitset_rejects_annotated_boards() {
+    to_bitset(&["*1"]);
+}
+
+#[test]
+fn mapped_with_default_map_matches_annotate() {
+    assert_eq!(
+        annotate_mapped(SNAPSHOT_RAW, &DEFAULT_COUNT_MAP),
+        SNAPSHOT_ANNOTATED
+    );
+}
+
+#[test]
+fn mapped_uses_the_supplied_glyphs() {
+    let letters = *b".abcdefgh";
+    assert_eq!(
+        annotate_mapped(SNAPSHOT_RAW, &letters),
+        vec!["****d***", "e*gf**fd", "*****d**", "*h**dbcc", "****b.a*"]
+    );
+    assert_eq!(annotate_mapped(&["   "], &letters), vec!["..."]);
+}
+
+#[test]
+#[should_panic(expected = "collides with mines")]
+fn mapped_rejects_a_mine_glyph() {
+    annotate_mapped(&[" "], b" 1234567*");
+}
+
+#[test]
+fn naive_matches_fixtures() {
+    assert_eq!(annotate_naive(SNAPSHOT_RAW), SNAPSHOT_ANNOTATED);
+    assert_eq!(annotate_naive(&[]), Vec::<String>::new());
+    assert_eq!(annotate_naive(&["", ""]), vec!["", ""]);
+    assert_eq!(annotate_naive(&["*"]), vec!["*"]);
+}
+
+#[test]
+fn naive_matches_annotate_on_random_boards() {
+    for seed in 0..200u64 {
+        let rows = 1 + (seed % 13) as usize;
+        let cols = 1 + (seed * 7 % 17) as usize;
+        let mines = (seed as usize * 31) % (rows * cols + 1);
+        let field = generate(rows, cols, mines, seed);
+        let raw: Vec<&str> = field.iter().map(String::as_str).collect();
+        assert_eq!(annotate_naive(&raw), annotate(&raw), "seed {seed}");
+    }
+}
+
+#[test]
+#[should_panic(expected = "invalid minefield")]
+fn naive_rejects_ragged_rows() {
+    annotate_naive(&["  ", " "]);
+}
+
+#[test]
+fn grid_new_is_all_empty() {
+    let grid = Grid::new(2, 3);
+    assert_eq!((grid.rows(), grid.cols()), (2, 3));
+    assert_eq!(grid.to_strings(), vec!["   ", "   "]);
+    assert_eq!(grid.annotate(), grid);
+    assert_eq!(Grid::new(0, 0).to_strings(), Vec::<String>::new());
+}
+
+#[test]
+#[should_panic(expected = "more cells than usize can index")]
+fn grid_new_rejects_dimensions_just_past_usize_max() {
+    let cols = 3;
+    Grid::new(usize::MAX / cols + 1, cols);
+}
+
+#[test]
+#[should_panic(expected = "more cells than usize can index")]
+fn grid_new_rejects_square_overflow() {
+    let side = 1usize << (usize::BITS / 2);
+    Grid::new(side, side);
+}
+
+#[test]
+fn shuffle_mines_keeps_dimensions_and_mine_count() {
+    let field = generate(9, 14, 30, 1);
+    let raw: Vec<&str> = field.iter().map(String::as_str).collect();
+    for seed in 0..20 {
+        let shuffled = shuffle_mines(&raw, seed);
+        assert_eq!(shuffled.len(), 9);
+        assert!(shuffled.iter().all(|row| row.len() == 14));
+        let shuffled: Vec<&str> = shuffled.iter().map(String::as_str).collect();
+        assert_eq!(count_mines(&shuffled), 30);
+    }
+}
+
+#[test]
+fn shuffle_mines_is_deterministic_per_seed() {
+    let field = generate(8, 8, 10, 3);
+    let raw: Vec<&str> = field.iter().map(String::as_str).collect();
+    assert_eq!(shuffle_mines(&raw, 5), shuffle_mines(&raw, 5));
+    assert_ne!(shuffle_mines(&raw, 5), shuffle_mines(&raw, 6));
+    assert_eq!(shuffle_mines(&["***", "***"], 9), vec!["***", "***"]);
+    assert_eq!(shuffle_mines(&[], 9), Vec::<String>::new());
+}
+
+#[test]
+#[should_panic(expected = "invalid minefield")]
+fn shuffle_mines_rejects_annotated_boards() {
+    shuffle_mines(&["*1"], 0);
+}